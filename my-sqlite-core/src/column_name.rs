@@ -59,6 +59,26 @@ impl Into<ColumnName> for String {
     }
 }
 
+pub fn push_quoted_identifier(dest: &mut String, name: &str) {
+    dest.push('"');
+
+    for c in name.chars() {
+        if c == '"' {
+            dest.push('"');
+        }
+
+        dest.push(c);
+    }
+
+    dest.push('"');
+}
+
+pub fn quote_identifier(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 2);
+    push_quoted_identifier(&mut result, name);
+    result
+}
+
 pub fn is_reserved(name: &str) -> bool {
     RESERVED.contains(name.to_lowercase().as_str())
 }
@@ -70,3 +90,15 @@ lazy_static::lazy_static! {
         result
     };
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(super::quote_identifier("volume"), "\"volume\"");
+        assert_eq!(
+            super::quote_identifier("x\") FROM t; --"),
+            "\"x\"\") FROM t; --\""
+        );
+    }
+}
@@ -1,27 +1,72 @@
-pub trait CountResult {
-    fn get_postgres_type() -> &'static str;
+use crate::DecodeError;
+
+pub trait CountResult: Sized {
+    fn from_sqlite_count(column_name: &str, value: i64) -> Result<Self, DecodeError>;
 }
 
-impl CountResult for u64 {
-    fn get_postgres_type() -> &'static str {
-        "bigint"
+impl CountResult for i64 {
+    fn from_sqlite_count(_column_name: &str, value: i64) -> Result<Self, DecodeError> {
+        Ok(value)
     }
 }
 
-impl CountResult for i32 {
-    fn get_postgres_type() -> &'static str {
-        "int"
+impl CountResult for u64 {
+    fn from_sqlite_count(column_name: &str, value: i64) -> Result<Self, DecodeError> {
+        try_from_count(column_name, value, "u64")
     }
 }
 
 impl CountResult for usize {
-    fn get_postgres_type() -> &'static str {
-        "bigint"
+    fn from_sqlite_count(column_name: &str, value: i64) -> Result<Self, DecodeError> {
+        try_from_count(column_name, value, "usize")
+    }
+}
+
+impl CountResult for u32 {
+    fn from_sqlite_count(column_name: &str, value: i64) -> Result<Self, DecodeError> {
+        try_from_count(column_name, value, "u32")
+    }
+}
+
+impl CountResult for i32 {
+    fn from_sqlite_count(column_name: &str, value: i64) -> Result<Self, DecodeError> {
+        try_from_count(column_name, value, "i32")
     }
 }
 
 impl CountResult for i16 {
-    fn get_postgres_type() -> &'static str {
-        "smallint"
+    fn from_sqlite_count(column_name: &str, value: i64) -> Result<Self, DecodeError> {
+        try_from_count(column_name, value, "i16")
+    }
+}
+
+fn try_from_count<T: TryFrom<i64>>(
+    column_name: &str,
+    value: i64,
+    type_name: &str,
+) -> Result<T, DecodeError> {
+    T::try_from(value).map_err(|_| {
+        DecodeError::invalid_value(
+            column_name,
+            format!("Count {} does not fit into {}", value, type_name),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CountResult;
+
+    #[test]
+    fn test_count_fits() {
+        assert_eq!(u32::from_sqlite_count("count", 42).unwrap(), 42);
+        assert_eq!(i16::from_sqlite_count("count", -5).unwrap(), -5);
+    }
+
+    #[test]
+    fn test_count_overflow_is_an_error() {
+        assert!(u64::from_sqlite_count("count", -1).is_err());
+        assert!(u32::from_sqlite_count("count", u32::MAX as i64 + 1).is_err());
+        assert!(i16::from_sqlite_count("count", i16::MAX as i64 + 1).is_err());
     }
 }
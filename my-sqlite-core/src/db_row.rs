@@ -1,5 +1,7 @@
 use async_sqlite::rusqlite::{types::FromSql, Row};
 
+use crate::DecodeError;
+
 pub struct DbRow<'s> {
     row: &'s Row<'s>,
     names: &'s [&'s str],
@@ -23,16 +25,22 @@ impl<'s> DbRow<'s> {
         None
     }
 
-    pub fn get<T: FromSql>(&self, name: &str) -> T {
-        let index = self.get_index(name);
+    pub fn try_get<T: FromSql>(&self, name: &str) -> Result<T, DecodeError> {
+        let index = match self.get_index(name) {
+            Some(index) => index,
+            None => return Err(DecodeError::column_not_found(name)),
+        };
 
-        if index.is_none() {
-            panic!("Column {} not found", name);
+        match self.row.get(index) {
+            Ok(result) => Ok(result),
+            Err(err) => Err(DecodeError::sqlite(name, err)),
         }
+    }
 
-        let index = index.unwrap();
-        let result = self.row.get(index).unwrap();
-
-        result
+    pub fn get<T: FromSql>(&self, name: &str) -> T {
+        match self.try_get(name) {
+            Ok(result) => result,
+            Err(err) => panic!("{}", err),
+        }
     }
 }
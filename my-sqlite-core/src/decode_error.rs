@@ -0,0 +1,93 @@
+#[derive(Debug)]
+pub enum DecodeErrorSource {
+    ColumnNotFound,
+    Sqlite(async_sqlite::rusqlite::Error),
+    Json(serde_json::Error),
+    InvalidValue(String),
+}
+
+impl std::fmt::Display for DecodeErrorSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ColumnNotFound => write!(f, "column not found"),
+            Self::Sqlite(err) => write!(f, "{}", err),
+            Self::Json(err) => write!(f, "invalid json: {}", err),
+            Self::InvalidValue(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for DecodeErrorSource {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Sqlite(err) => Some(err),
+            Self::Json(err) => Some(err),
+            Self::ColumnNotFound => None,
+            Self::InvalidValue(_) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DecodeError {
+    pub column: String,
+    pub source: DecodeErrorSource,
+}
+
+impl DecodeError {
+    pub fn column_not_found(column: &str) -> Self {
+        Self {
+            column: column.to_string(),
+            source: DecodeErrorSource::ColumnNotFound,
+        }
+    }
+
+    pub fn sqlite(column: &str, err: async_sqlite::rusqlite::Error) -> Self {
+        Self {
+            column: column.to_string(),
+            source: DecodeErrorSource::Sqlite(err),
+        }
+    }
+
+    pub fn json(column: &str, err: serde_json::Error) -> Self {
+        Self {
+            column: column.to_string(),
+            source: DecodeErrorSource::Json(err),
+        }
+    }
+
+    pub fn invalid_value(column: &str, message: impl Into<String>) -> Self {
+        Self {
+            column: column.to_string(),
+            source: DecodeErrorSource::InvalidValue(message.into()),
+        }
+    }
+
+    pub fn null_value(column: &str, type_name: &str) -> Self {
+        Self::invalid_value(
+            column,
+            format!("Null value can not be converted into {}", type_name),
+        )
+    }
+
+    pub fn into_sql_lite_error(self, row: usize) -> crate::SqlLiteError {
+        crate::SqlLiteError::Decode {
+            column: self.column,
+            row,
+            source: self.source,
+            sql: None,
+        }
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Field: {}. {}", self.column, self.source)
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::SqlLiteError;
+
+pub struct DecodedRows<TEntity> {
+    pub items: Vec<TEntity>,
+    pub errors: Vec<SqlLiteError>,
+}
+
+impl<TEntity> DecodedRows<TEntity> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
@@ -1,17 +1,378 @@
+use async_sqlite::rusqlite::{ffi, ErrorCode};
+
+use crate::DecodeErrorSource;
+
 #[derive(Debug)]
 pub enum SqlLiteError {
     SqlLiteError(async_sqlite::Error),
     RusSqliteError(async_sqlite::rusqlite::Error),
+    UniqueViolation {
+        table: String,
+        index: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    ForeignKeyViolation {
+        message: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    CheckViolation {
+        constraint: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    NotNullViolation {
+        table: String,
+        column: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    NotFound {
+        sql: Option<String>,
+    },
+    Busy {
+        message: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    ReadOnly {
+        message: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    SchemaMismatch {
+        message: String,
+        sql: Option<String>,
+        source: async_sqlite::rusqlite::Error,
+    },
+    Decode {
+        column: String,
+        row: usize,
+        source: DecodeErrorSource,
+        sql: Option<String>,
+    },
+    Timeout {
+        sql: Option<String>,
+    },
+    Interrupted {
+        sql: Option<String>,
+    },
+    InvalidCursor {
+        message: String,
+    },
+    InvalidArgument {
+        message: String,
+    },
+}
+
+impl SqlLiteError {
+    pub fn get_sql(&self) -> Option<&str> {
+        match self {
+            Self::SqlLiteError(_) => None,
+            Self::RusSqliteError(_) => None,
+            Self::UniqueViolation { sql, .. } => sql.as_deref(),
+            Self::ForeignKeyViolation { sql, .. } => sql.as_deref(),
+            Self::CheckViolation { sql, .. } => sql.as_deref(),
+            Self::NotNullViolation { sql, .. } => sql.as_deref(),
+            Self::NotFound { sql } => sql.as_deref(),
+            Self::Busy { sql, .. } => sql.as_deref(),
+            Self::ReadOnly { sql, .. } => sql.as_deref(),
+            Self::SchemaMismatch { sql, .. } => sql.as_deref(),
+            Self::Decode { sql, .. } => sql.as_deref(),
+            Self::Timeout { sql } => sql.as_deref(),
+            Self::Interrupted { sql } => sql.as_deref(),
+            Self::InvalidCursor { .. } => None,
+            Self::InvalidArgument { .. } => None,
+        }
+    }
+
+    pub fn with_sql(mut self, src: &str, redact: bool) -> Self {
+        let value = if redact {
+            redact_sql(src)
+        } else {
+            src.to_string()
+        };
+
+        match &mut self {
+            Self::SqlLiteError(_) => {}
+            Self::RusSqliteError(_) => {}
+            Self::UniqueViolation { sql, .. } => *sql = Some(value),
+            Self::ForeignKeyViolation { sql, .. } => *sql = Some(value),
+            Self::CheckViolation { sql, .. } => *sql = Some(value),
+            Self::NotNullViolation { sql, .. } => *sql = Some(value),
+            Self::NotFound { sql } => *sql = Some(value),
+            Self::Busy { sql, .. } => *sql = Some(value),
+            Self::ReadOnly { sql, .. } => *sql = Some(value),
+            Self::SchemaMismatch { sql, .. } => *sql = Some(value),
+            Self::Decode { sql, .. } => *sql = Some(value),
+            Self::Timeout { sql } => *sql = Some(value),
+            Self::Interrupted { sql } => *sql = Some(value),
+            Self::InvalidCursor { .. } => {}
+            Self::InvalidArgument { .. } => {}
+        }
+
+        self
+    }
+
+    pub fn is_unique_violation(&self) -> bool {
+        matches!(self, Self::UniqueViolation { .. })
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. })
+    }
+
+    fn from_rusqlite(err: async_sqlite::rusqlite::Error) -> Self {
+        let (code, extended_code, message) = match &err {
+            async_sqlite::rusqlite::Error::QueryReturnedNoRows => {
+                return Self::NotFound { sql: None };
+            }
+            async_sqlite::rusqlite::Error::SqliteFailure(error, message) => (
+                error.code,
+                error.extended_code,
+                message.clone().unwrap_or_default(),
+            ),
+            async_sqlite::rusqlite::Error::SqlInputError { error, msg, .. } => {
+                (error.code, error.extended_code, msg.clone())
+            }
+            _ => return Self::RusSqliteError(err),
+        };
+
+        match code {
+            ErrorCode::ConstraintViolation => match extended_code {
+                ffi::SQLITE_CONSTRAINT_UNIQUE | ffi::SQLITE_CONSTRAINT_PRIMARYKEY => {
+                    let (table, index) = parse_constraint_target(message.as_str());
+                    Self::UniqueViolation {
+                        table,
+                        index,
+                        sql: None,
+                        source: err,
+                    }
+                }
+                ffi::SQLITE_CONSTRAINT_FOREIGNKEY => Self::ForeignKeyViolation {
+                    message,
+                    sql: None,
+                    source: err,
+                },
+                ffi::SQLITE_CONSTRAINT_CHECK => Self::CheckViolation {
+                    constraint: get_constraint_details(message.as_str()).to_string(),
+                    sql: None,
+                    source: err,
+                },
+                ffi::SQLITE_CONSTRAINT_NOTNULL => {
+                    let (table, column) = parse_constraint_target(message.as_str());
+                    Self::NotNullViolation {
+                        table,
+                        column,
+                        sql: None,
+                        source: err,
+                    }
+                }
+                _ => Self::RusSqliteError(err),
+            },
+            ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked => Self::Busy {
+                message,
+                sql: None,
+                source: err,
+            },
+            ErrorCode::ReadOnly => Self::ReadOnly {
+                message,
+                sql: None,
+                source: err,
+            },
+            ErrorCode::SchemaChanged => Self::SchemaMismatch {
+                message,
+                sql: None,
+                source: err,
+            },
+            ErrorCode::OperationInterrupted => Self::Interrupted { sql: None },
+            ErrorCode::Unknown if is_schema_mismatch_message(message.as_str()) => {
+                Self::SchemaMismatch {
+                    message,
+                    sql: None,
+                    source: err,
+                }
+            }
+            _ => Self::RusSqliteError(err),
+        }
+    }
+}
+
+fn get_constraint_details(message: &str) -> &str {
+    match message.find(':') {
+        Some(index) => message[index + 1..].trim(),
+        None => message,
+    }
+}
+
+fn parse_constraint_target(message: &str) -> (String, String) {
+    let mut table = None;
+    let mut columns = Vec::new();
+
+    for itm in get_constraint_details(message).split(',') {
+        let itm = itm.trim();
+        match itm.split_once('.') {
+            Some((table_name, column_name)) => {
+                if table.is_none() {
+                    table = Some(table_name.to_string());
+                }
+                columns.push(column_name);
+            }
+            None => columns.push(itm),
+        }
+    }
+
+    (table.unwrap_or_default(), columns.join(","))
+}
+
+fn is_schema_mismatch_message(message: &str) -> bool {
+    message.starts_with("no such table")
+        || message.starts_with("no such column")
+        || message.contains("has no column named")
+}
+
+pub fn redact_sql(sql: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    let mut prev_is_ident = false;
+
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            while let Some(next) = chars.next() {
+                if next == '\'' {
+                    if chars.peek() == Some(&'\'') {
+                        chars.next();
+                        continue;
+                    }
+                    break;
+                }
+            }
+
+            result.push('?');
+            prev_is_ident = false;
+            continue;
+        }
+
+        if c.is_ascii_digit() && !prev_is_ident {
+            while let Some(next) = chars.peek() {
+                if next.is_ascii_digit() || *next == '.' {
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+
+            result.push('?');
+            prev_is_ident = false;
+            continue;
+        }
+
+        prev_is_ident = c.is_alphanumeric() || c == '_' || c == '$';
+        result.push(c);
+    }
+
+    result
+}
+
+impl std::fmt::Display for SqlLiteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SqlLiteError(err) => write!(f, "{}", err)?,
+            Self::RusSqliteError(err) => write!(f, "{}", err)?,
+            Self::UniqueViolation { table, index, .. } => {
+                write!(f, "Unique constraint violation. Table: {}. Index: {}", table, index)?
+            }
+            Self::ForeignKeyViolation { message, .. } => {
+                write!(f, "Foreign key constraint violation: {}", message)?
+            }
+            Self::CheckViolation { constraint, .. } => {
+                write!(f, "Check constraint violation: {}", constraint)?
+            }
+            Self::NotNullViolation { table, column, .. } => write!(
+                f,
+                "Not null constraint violation. Table: {}. Column: {}",
+                table, column
+            )?,
+            Self::NotFound { .. } => write!(f, "Query returned no rows")?,
+            Self::Busy { message, .. } => write!(f, "Database is busy: {}", message)?,
+            Self::ReadOnly { message, .. } => write!(f, "Database is read only: {}", message)?,
+            Self::SchemaMismatch { message, .. } => write!(f, "Schema mismatch: {}", message)?,
+            Self::Decode {
+                column,
+                row,
+                source,
+                ..
+            } => write!(f, "Can not decode row {}. Field: {}. {}", row, column, source)?,
+            Self::Timeout { .. } => write!(f, "Query timed out")?,
+            Self::Interrupted { .. } => write!(f, "Query was interrupted")?,
+            Self::InvalidCursor { message } => write!(f, "Invalid page cursor: {}", message)?,
+            Self::InvalidArgument { message } => write!(f, "Invalid argument: {}", message)?,
+        }
+
+        if let Some(sql) = self.get_sql() {
+            write!(f, ". Sql: {}", sql)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for SqlLiteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::SqlLiteError(err) => Some(err),
+            Self::RusSqliteError(err) => Some(err),
+            Self::UniqueViolation { source, .. } => Some(source),
+            Self::ForeignKeyViolation { source, .. } => Some(source),
+            Self::CheckViolation { source, .. } => Some(source),
+            Self::NotNullViolation { source, .. } => Some(source),
+            Self::Busy { source, .. } => Some(source),
+            Self::ReadOnly { source, .. } => Some(source),
+            Self::SchemaMismatch { source, .. } => Some(source),
+            Self::Decode { source, .. } => Some(source),
+            Self::NotFound { .. } => None,
+            Self::Timeout { .. } => None,
+            Self::Interrupted { .. } => None,
+            Self::InvalidCursor { .. } => None,
+            Self::InvalidArgument { .. } => None,
+        }
+    }
 }
 
 impl From<async_sqlite::Error> for SqlLiteError {
     fn from(value: async_sqlite::Error) -> Self {
-        Self::SqlLiteError(value)
+        match value {
+            async_sqlite::Error::Rusqlite(err) => Self::from_rusqlite(err),
+            _ => Self::SqlLiteError(value),
+        }
     }
 }
 
 impl From<async_sqlite::rusqlite::Error> for SqlLiteError {
     fn from(value: async_sqlite::rusqlite::Error) -> Self {
-        Self::RusSqliteError(value)
+        Self::from_rusqlite(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unique_constraint() {
+        let (table, index) =
+            parse_constraint_target("UNIQUE constraint failed: users.email, users.tenant_id");
+
+        assert_eq!("users", table);
+        assert_eq!("email,tenant_id", index);
+    }
+
+    #[test]
+    fn test_redact_sql() {
+        assert_eq!(
+            "SELECT id FROM t1 WHERE name=? AND created>=? AND id=$1",
+            redact_sql("SELECT id FROM t1 WHERE name='it''s' AND created>=1609556645123456 AND id=$1")
+        );
     }
 }
@@ -0,0 +1,185 @@
+use async_sqlite::rusqlite::types::Value;
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::{
+    sql::{SelectBuilder, SelectFieldValue, SqlUpdateValue, SqlValues},
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    sql_update::SqlUpdateValueProvider,
+    table_schema::{SqlTypeProvider, TableColumnType},
+    DateTimeStorage, DecodeError, RenderFullWhereCondition, SqlValueMetadata,
+    SqlWhereValueProvider,
+};
+
+const DEFAULT_STORAGE: DateTimeStorage = DateTimeStorage::IsoText { precision: 6 };
+
+fn get_storage(metadata: &Option<SqlValueMetadata>) -> DateTimeStorage {
+    DateTimeStorage::from_metadata_or_default(metadata, DEFAULT_STORAGE)
+}
+
+fn read_unix_microseconds(
+    row: &crate::DbRow,
+    column_name: DbColumnName,
+    metadata: &Option<SqlValueMetadata>,
+) -> Result<Option<i64>, DecodeError> {
+    let value: Value = row.try_get(column_name.db_column_name)?;
+
+    match get_storage(metadata).try_decode(value) {
+        Ok(result) => Ok(result),
+        Err(err) => Err(DecodeError::invalid_value(column_name.db_column_name, err)),
+    }
+}
+
+fn from_unix_microseconds(
+    unix_microseconds: i64,
+    column_name: DbColumnName,
+) -> Result<DateTime<Utc>, DecodeError> {
+    match DateTime::<Utc>::from_timestamp_micros(unix_microseconds) {
+        Some(result) => Ok(result),
+        None => Err(DecodeError::invalid_value(
+            column_name.db_column_name,
+            format!("Value {} is out of range for DateTime<Utc>", unix_microseconds),
+        )),
+    }
+}
+
+impl SqlTypeProvider for DateTime<Utc> {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        get_storage(&metadata).get_table_column_type()
+    }
+}
+
+impl SelectValueProvider for DateTime<Utc> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Field(field_name));
+    }
+}
+
+impl<'s> FromDbRow<'s, DateTime<Utc>> for DateTime<Utc> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<DateTime<Utc>, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(column_name.db_column_name, "DateTime<Utc>")),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<DateTime<Utc>>, DecodeError> {
+        match read_unix_microseconds(row, column_name, metadata)? {
+            Some(unix_microseconds) => Ok(Some(from_unix_microseconds(unix_microseconds, column_name)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl SqlUpdateValueProvider for DateTime<Utc> {
+    fn get_update_value(
+        &self,
+        _params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        get_storage(metadata).encode(self.timestamp_micros())
+    }
+}
+
+impl SqlWhereValueProvider for DateTime<Utc> {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        _params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        get_storage(metadata).fill_where_value(
+            self.timestamp_micros(),
+            full_where_condition,
+            sql,
+            metadata,
+        )
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
+
+impl SqlTypeProvider for NaiveDateTime {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        get_storage(&metadata).get_table_column_type()
+    }
+}
+
+impl SelectValueProvider for NaiveDateTime {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Field(field_name));
+    }
+}
+
+impl<'s> FromDbRow<'s, NaiveDateTime> for NaiveDateTime {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<NaiveDateTime, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(column_name.db_column_name, "NaiveDateTime")),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<NaiveDateTime>, DecodeError> {
+        match read_unix_microseconds(row, column_name, metadata)? {
+            Some(unix_microseconds) => Ok(Some(from_unix_microseconds(unix_microseconds, column_name)?.naive_utc())),
+            None => Ok(None),
+        }
+    }
+}
+
+impl SqlUpdateValueProvider for NaiveDateTime {
+    fn get_update_value(
+        &self,
+        _params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        get_storage(metadata).encode(self.and_utc().timestamp_micros())
+    }
+}
+
+impl SqlWhereValueProvider for NaiveDateTime {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        _params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        get_storage(metadata).fill_where_value(
+            self.and_utc().timestamp_micros(),
+            full_where_condition,
+            sql,
+            metadata,
+        )
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use async_sqlite::rusqlite::types::Value;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+
+use crate::{
+    sql::{SelectBuilder, SelectFieldValue, SqlUpdateValue, SqlValues},
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    sql_update::SqlUpdateValueProvider,
+    table_schema::{SqlTypeProvider, TableColumnType},
+    DecodeError, RenderFullWhereCondition, SqlValueMetadata, SqlWhereValueProvider,
+};
+
+// Decimal is stored as TEXT by default to keep it lossless. SQLite compares TEXT
+// lexicographically ("10" < "9"), so range operators are only supported with
+// #[sql_type("real")].
+fn is_real(metadata: &Option<SqlValueMetadata>) -> bool {
+    match super::get_sql_type(metadata) {
+        Some("real") => true,
+        Some("text") | None => false,
+        Some(sql_type) => panic!("Unknown sql_type for Decimal: {}", sql_type),
+    }
+}
+
+fn to_update_value(
+    value: &Decimal,
+    params: &mut SqlValues,
+    metadata: &Option<SqlValueMetadata>,
+) -> SqlUpdateValue {
+    if is_real(metadata) {
+        let value = match value.to_f64() {
+            Some(value) => value,
+            None => panic!("Decimal value {} can not be converted into f64", value),
+        };
+
+        return SqlUpdateValue::NonStringValue(format!("{:?}", value));
+    }
+
+    let index = params.push(value.to_string().into());
+    SqlUpdateValue::Index(index)
+}
+
+impl SqlTypeProvider for Decimal {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        if is_real(&metadata) {
+            TableColumnType::Double
+        } else {
+            TableColumnType::Text
+        }
+    }
+}
+
+impl SelectValueProvider for Decimal {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Field(field_name));
+    }
+}
+
+impl<'s> FromDbRow<'s, Decimal> for Decimal {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Decimal, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(column_name.db_column_name, "Decimal")),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<Decimal>, DecodeError> {
+        let value: Value = row.try_get(column_name.db_column_name)?;
+
+        let result = match &value {
+            Value::Null => return Ok(None),
+            Value::Text(value) => Decimal::from_str(value.as_str()).ok(),
+            Value::Integer(value) => Some(Decimal::from(*value)),
+            Value::Real(value) => Decimal::from_f64(*value),
+            Value::Blob(_) => None,
+        };
+
+        match result {
+            Some(result) => Ok(Some(result)),
+            None => Err(DecodeError::invalid_value(
+                column_name.db_column_name,
+                format!("Value {:?} can not be converted into Decimal", value),
+            )),
+        }
+    }
+}
+
+impl SqlUpdateValueProvider for Decimal {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        to_update_value(self, params, metadata)
+    }
+}
+
+impl SqlWhereValueProvider for Decimal {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        if !is_real(metadata) {
+            if let Some(operator) = metadata.as_ref().and_then(|metadata| metadata.operator) {
+                if matches!(operator, ">" | "<" | ">=" | "<=") {
+                    panic!(
+                        "Operator {} is not supported for Decimal stored as text. Use #[sql_type(\"real\")]",
+                        operator
+                    );
+                }
+            }
+        }
+
+        let value = to_update_value(self, params, metadata);
+
+        if let Some(full_where_condition) = full_where_condition {
+            full_where_condition.render_param_name(sql, "=", metadata);
+        }
+
+        value.write(sql);
+
+        true
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
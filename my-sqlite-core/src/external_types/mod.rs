@@ -0,0 +1,13 @@
+#[cfg(feature = "with-chrono")]
+mod chrono_types;
+#[cfg(feature = "with-rust-decimal")]
+mod decimal_type;
+#[cfg(feature = "with-time")]
+mod time_types;
+#[cfg(feature = "with-uuid")]
+mod uuid_type;
+
+#[cfg(any(feature = "with-uuid", feature = "with-rust-decimal"))]
+fn get_sql_type(metadata: &Option<crate::SqlValueMetadata>) -> Option<&'static str> {
+    metadata.as_ref()?.sql_type
+}
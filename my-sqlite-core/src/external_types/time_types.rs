@@ -0,0 +1,106 @@
+use async_sqlite::rusqlite::types::Value;
+use time::OffsetDateTime;
+
+use crate::{
+    sql::{SelectBuilder, SelectFieldValue, SqlUpdateValue, SqlValues},
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    sql_update::SqlUpdateValueProvider,
+    table_schema::{SqlTypeProvider, TableColumnType},
+    DateTimeStorage, DecodeError, RenderFullWhereCondition, SqlValueMetadata,
+    SqlWhereValueProvider,
+};
+
+const DEFAULT_STORAGE: DateTimeStorage = DateTimeStorage::IsoText { precision: 6 };
+
+fn get_storage(metadata: &Option<SqlValueMetadata>) -> DateTimeStorage {
+    DateTimeStorage::from_metadata_or_default(metadata, DEFAULT_STORAGE)
+}
+
+fn to_unix_microseconds(value: &OffsetDateTime) -> i64 {
+    (value.unix_timestamp_nanos() / 1_000) as i64
+}
+
+impl SqlTypeProvider for OffsetDateTime {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        get_storage(&metadata).get_table_column_type()
+    }
+}
+
+impl SelectValueProvider for OffsetDateTime {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Field(field_name));
+    }
+}
+
+impl<'s> FromDbRow<'s, OffsetDateTime> for OffsetDateTime {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<OffsetDateTime, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(column_name.db_column_name, "OffsetDateTime")),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<OffsetDateTime>, DecodeError> {
+        let value: Value = row.try_get(column_name.db_column_name)?;
+
+        let unix_microseconds = match get_storage(metadata).try_decode(value) {
+            Ok(Some(result)) => result,
+            Ok(None) => return Ok(None),
+            Err(err) => return Err(DecodeError::invalid_value(column_name.db_column_name, err)),
+        };
+
+        match OffsetDateTime::from_unix_timestamp_nanos(unix_microseconds as i128 * 1_000) {
+            Ok(result) => Ok(Some(result)),
+            Err(err) => Err(DecodeError::invalid_value(
+                column_name.db_column_name,
+                format!(
+                    "Value {} can not be converted into OffsetDateTime. Err: {}",
+                    unix_microseconds, err
+                ),
+            )),
+        }
+    }
+}
+
+impl SqlUpdateValueProvider for OffsetDateTime {
+    fn get_update_value(
+        &self,
+        _params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        get_storage(metadata).encode(to_unix_microseconds(self))
+    }
+}
+
+impl SqlWhereValueProvider for OffsetDateTime {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        _params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        get_storage(metadata).fill_where_value(
+            to_unix_microseconds(self),
+            full_where_condition,
+            sql,
+            metadata,
+        )
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,122 @@
+use async_sqlite::rusqlite::types::Value;
+use uuid::Uuid;
+
+use crate::{
+    sql::{SelectBuilder, SelectFieldValue, SqlUpdateValue, SqlValues},
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    sql_update::SqlUpdateValueProvider,
+    table_schema::{SqlTypeProvider, TableColumnType},
+    DecodeError, RenderFullWhereCondition, SqlValueMetadata, SqlWhereValueProvider,
+};
+
+fn is_blob(metadata: &Option<SqlValueMetadata>) -> bool {
+    match super::get_sql_type(metadata) {
+        Some("blob") => true,
+        Some("text") | None => false,
+        Some(sql_type) => panic!("Unknown sql_type for Uuid: {}", sql_type),
+    }
+}
+
+fn push_param(value: &Uuid, params: &mut SqlValues, metadata: &Option<SqlValueMetadata>) -> usize {
+    if is_blob(metadata) {
+        params.push_blob(value.as_bytes().to_vec())
+    } else {
+        params.push(value.to_string().into())
+    }
+}
+
+impl SqlTypeProvider for Uuid {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        if is_blob(&metadata) {
+            TableColumnType::Blob
+        } else {
+            TableColumnType::Text
+        }
+    }
+}
+
+impl SelectValueProvider for Uuid {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Field(field_name));
+    }
+}
+
+impl<'s> FromDbRow<'s, Uuid> for Uuid {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Uuid, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(column_name.db_column_name, "Uuid")),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<Uuid>, DecodeError> {
+        let value: Value = row.try_get(column_name.db_column_name)?;
+
+        let result = match value {
+            Value::Null => return Ok(None),
+            Value::Blob(value) => Uuid::from_slice(value.as_slice()),
+            Value::Text(value) => Uuid::parse_str(value.as_str()),
+            _ => {
+                return Err(DecodeError::invalid_value(
+                    column_name.db_column_name,
+                    format!("Value {:?} can not be converted into Uuid", value),
+                ))
+            }
+        };
+
+        match result {
+            Ok(result) => Ok(Some(result)),
+            Err(err) => Err(DecodeError::invalid_value(
+                column_name.db_column_name,
+                format!("Can not convert value into Uuid. Err: {}", err),
+            )),
+        }
+    }
+}
+
+impl SqlUpdateValueProvider for Uuid {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        let index = push_param(self, params, metadata);
+        SqlUpdateValue::Index(index)
+    }
+}
+
+impl SqlWhereValueProvider for Uuid {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        if let Some(full_where_condition) = full_where_condition {
+            full_where_condition.render_param_name(sql, "=", metadata);
+        }
+
+        let index = push_param(self, params, metadata);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
+
+        true
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
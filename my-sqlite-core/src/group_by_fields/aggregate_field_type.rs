@@ -0,0 +1,59 @@
+use async_sqlite::rusqlite::types::{FromSql, Value, ValueRef};
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{DateTimeStorage, DecodeError, GroupByFieldType};
+
+pub trait AggregateFieldType: Sized {
+    fn render_aggregate_statement(function: &str, column_name: &str) -> String;
+
+    fn try_from_aggregate_value(
+        column_name: &str,
+        value: Value,
+    ) -> Result<Option<Self>, DecodeError>;
+}
+
+impl<T: GroupByFieldType + FromSql> AggregateFieldType for T {
+    fn render_aggregate_statement(function: &str, column_name: &str) -> String {
+        crate::render_group_by_statement(function, column_name, T::DB_SQL_TYPE)
+    }
+
+    fn try_from_aggregate_value(
+        column_name: &str,
+        value: Value,
+    ) -> Result<Option<Self>, DecodeError> {
+        Option::<T>::column_result(ValueRef::from(&value))
+            .map_err(|err| DecodeError::invalid_value(column_name, err.to_string()))
+    }
+}
+
+// Timestamps may be stored either as unix microseconds (bigint) or as ISO text (timestamp).
+// MIN and MAX keep the stored value, since ISO text written by the crate sorts chronologically.
+// SUM and AVG convert text values into unix microseconds first, with millisecond precision.
+impl AggregateFieldType for DateTimeAsMicroseconds {
+    fn render_aggregate_statement(function: &str, column_name: &str) -> String {
+        match function {
+            "MIN" | "MAX" => format!("{}({})", function, column_name),
+            _ => {
+                let text_as_microseconds = format!(
+                    "(CAST(strftime('%s',{}) AS INTEGER)*1000000+CAST(substr(strftime('%f',{}),4) AS INTEGER)*1000)",
+                    column_name, column_name
+                );
+
+                format!(
+                    "{}(CASE WHEN typeof({}) = 'text' THEN {} ELSE {} END)",
+                    function, column_name, text_as_microseconds, column_name
+                )
+            }
+        }
+    }
+
+    fn try_from_aggregate_value(
+        column_name: &str,
+        value: Value,
+    ) -> Result<Option<Self>, DecodeError> {
+        match DateTimeStorage::UnixMicroseconds.try_decode(value) {
+            Ok(result) => Ok(result.map(DateTimeAsMicroseconds::new)),
+            Err(err) => Err(DecodeError::invalid_value(column_name, err)),
+        }
+    }
+}
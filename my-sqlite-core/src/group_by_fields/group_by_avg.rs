@@ -3,7 +3,7 @@ use async_sqlite::rusqlite::types::FromSql;
 use crate::{
     sql::SelectBuilder,
     sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
-    GroupByFieldType, SqlValueMetadata,
+    DecodeError, GroupByFieldType, SqlValueMetadata,
 };
 
 #[derive(Debug)]
@@ -35,7 +35,8 @@ impl<T: std::fmt::Debug + GroupByFieldType + Send + Sync + 'static> SelectValueP
 
         sql.push(crate::sql::SelectFieldValue::GroupByField {
             field_name,
-            statement: format!("AVG({})::{}", field_name.db_column_name, sql_type).into(),
+            statement: crate::render_group_by_statement("AVG", field_name.db_column_name, sql_type)
+                .into(),
         });
     }
 }
@@ -43,21 +44,21 @@ impl<T: std::fmt::Debug + GroupByFieldType + Send + Sync + 'static> SelectValueP
 impl<'s, T: std::fmt::Debug + Copy + FromSql + Send + Sync + 'static> FromDbRow<'s, GroupByAvg<T>>
     for GroupByAvg<T>
 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> GroupByAvg<T> {
-        let value: T = row.get(column_name.db_column_name);
-        GroupByAvg(value)
+    ) -> Result<GroupByAvg<T>, DecodeError> {
+        let value: T = row.try_get(column_name.db_column_name)?;
+        Ok(GroupByAvg(value))
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<GroupByAvg<T>> {
-        let result: Option<T> = row.get(column_name.db_column_name);
-        Some(GroupByAvg(result?))
+    ) -> Result<Option<GroupByAvg<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(GroupByAvg))
     }
 }
@@ -0,0 +1,89 @@
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub const DEFAULT_GROUP_CONCAT_SEPARATOR: &str = ",";
+
+pub struct GroupByConcat {
+    value: String,
+    separator: &'static str,
+}
+
+impl GroupByConcat {
+    pub fn get_value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    pub fn get_separator(&self) -> &'static str {
+        self.separator
+    }
+
+    pub fn get_items(&self) -> Vec<&str> {
+        if self.value.is_empty() {
+            return vec![];
+        }
+
+        self.value.split(self.separator).collect()
+    }
+
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
+fn get_separator(metadata: &Option<SqlValueMetadata>) -> &'static str {
+    if let Some(metadata) = metadata {
+        if let Some(separator) = metadata.separator {
+            return separator;
+        }
+    }
+
+    DEFAULT_GROUP_CONCAT_SEPARATOR
+}
+
+impl SelectValueProvider for GroupByConcat {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let separator = get_separator(metadata).replace('\'', "''");
+
+        sql.push(crate::sql::SelectFieldValue::GroupByField {
+            field_name,
+            statement: format!(
+                "group_concat({},'{}')",
+                field_name.db_column_name, separator
+            )
+            .into(),
+        });
+    }
+}
+
+impl<'s> FromDbRow<'s, GroupByConcat> for GroupByConcat {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<GroupByConcat, DecodeError> {
+        let value: String = row.try_get(column_name.db_column_name)?;
+        Ok(GroupByConcat {
+            value,
+            separator: get_separator(metadata),
+        })
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<GroupByConcat>, DecodeError> {
+        let result: Option<String> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(|value| GroupByConcat {
+            value,
+            separator: get_separator(metadata),
+        }))
+    }
+}
@@ -3,7 +3,7 @@ use async_sqlite::rusqlite::types::FromSql;
 use crate::{
     sql::SelectBuilder,
     sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
-    GroupByFieldType, SqlValueMetadata,
+    DecodeError, GroupByFieldType, SqlValueMetadata,
 };
 
 pub struct GroupByCount<T: Send + Sync + 'static>(T);
@@ -40,20 +40,21 @@ impl<'s, T: GroupByFieldType + Send + Sync + 'static> SelectValueProvider for Gr
 impl<'s, T: Copy + FromSql + Send + Sync + 'static> FromDbRow<'s, GroupByCount<T>>
     for GroupByCount<T>
 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> GroupByCount<T> {
-        GroupByCount(row.get(column_name.db_column_name))
+    ) -> Result<GroupByCount<T>, DecodeError> {
+        let value: T = row.try_get(column_name.db_column_name)?;
+        Ok(GroupByCount(value))
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<GroupByCount<T>> {
-        let result: Option<T> = row.get(column_name.db_column_name);
-        Some(GroupByCount(result?))
+    ) -> Result<Option<GroupByCount<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(GroupByCount))
     }
 }
@@ -0,0 +1,66 @@
+use async_sqlite::rusqlite::types::FromSql;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, GroupByFieldType, SqlValueMetadata,
+};
+
+pub struct GroupByCountDistinct<T: Send + Sync + 'static>(T);
+
+impl<'s, T: Copy + FromSql + Send + Sync + 'static> GroupByCountDistinct<T> {
+    pub fn get_value(&self) -> T {
+        self.0
+    }
+}
+
+impl<'s, T: GroupByFieldType + Send + Sync + 'static> SelectValueProvider
+    for GroupByCountDistinct<T>
+{
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let sql_type = if let Some(metadata) = metadata {
+            if let Some(sql_type) = metadata.sql_type {
+                sql_type
+            } else {
+                T::DB_SQL_TYPE
+            }
+        } else {
+            T::DB_SQL_TYPE
+        };
+
+        sql.push(crate::sql::SelectFieldValue::GroupByField {
+            field_name,
+            statement: format!(
+                "cast(COUNT(DISTINCT {}) as {})",
+                field_name.db_column_name, sql_type
+            )
+            .into(),
+        });
+    }
+}
+
+impl<'s, T: Copy + FromSql + Send + Sync + 'static> FromDbRow<'s, GroupByCountDistinct<T>>
+    for GroupByCountDistinct<T>
+{
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<GroupByCountDistinct<T>, DecodeError> {
+        let value: T = row.try_get(column_name.db_column_name)?;
+        Ok(GroupByCountDistinct(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<GroupByCountDistinct<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(GroupByCountDistinct))
+    }
+}
@@ -6,6 +6,14 @@ impl GroupByFieldType for i64 {
     const DB_SQL_TYPE: &'static str = "bigint";
 }
 
+impl GroupByFieldType for u64 {
+    const DB_SQL_TYPE: &'static str = "bigint";
+}
+
+impl GroupByFieldType for u32 {
+    const DB_SQL_TYPE: &'static str = "bigint";
+}
+
 impl GroupByFieldType for i32 {
     const DB_SQL_TYPE: &'static str = "int";
 }
@@ -21,3 +29,11 @@ impl GroupByFieldType for f32 {
 impl GroupByFieldType for f64 {
     const DB_SQL_TYPE: &'static str = "double precision";
 }
+
+impl GroupByFieldType for String {
+    const DB_SQL_TYPE: &'static str = "text";
+}
+
+pub fn render_group_by_statement(function: &str, column_name: &str, sql_type: &str) -> String {
+    format!("cast({}({}) as {})", function, column_name, sql_type)
+}
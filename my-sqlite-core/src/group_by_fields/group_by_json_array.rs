@@ -0,0 +1,61 @@
+use serde::de::DeserializeOwned;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct GroupByJsonArray<T>(Vec<T>);
+
+impl<T> GroupByJsonArray<T> {
+    pub fn get_value(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    pub fn into_value(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> SelectValueProvider for GroupByJsonArray<T> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(crate::sql::SelectFieldValue::GroupByField {
+            field_name,
+            statement: format!("json_group_array({})", field_name.db_column_name).into(),
+        });
+    }
+}
+
+impl<'s, T: DeserializeOwned> FromDbRow<'s, GroupByJsonArray<T>> for GroupByJsonArray<T> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<GroupByJsonArray<T>, DecodeError> {
+        let value: String = row.try_get(column_name.db_column_name)?;
+        let items = crate::json_column::parse_json(column_name.db_column_name, value.as_str())?;
+        Ok(GroupByJsonArray(items))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<GroupByJsonArray<T>>, DecodeError> {
+        let value: Option<String> = row.try_get(column_name.db_column_name)?;
+
+        match value {
+            Some(value) => {
+                let items =
+                    crate::json_column::parse_json(column_name.db_column_name, value.as_str())?;
+                Ok(Some(GroupByJsonArray(items)))
+            }
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::{collections::HashMap, hash::Hash};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct GroupByJsonObject<K, V>(HashMap<K, V>);
+
+impl<K, V> GroupByJsonObject<K, V> {
+    pub fn get_value(&self) -> &HashMap<K, V> {
+        &self.0
+    }
+
+    pub fn into_value(self) -> HashMap<K, V> {
+        self.0
+    }
+}
+
+impl<K, V> SelectValueProvider for GroupByJsonObject<K, V> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let key_column = match metadata.as_ref().and_then(|metadata| metadata.json_object_key) {
+            Some(key_column) => key_column,
+            None => panic!(
+                "GroupByJsonObject field {} requires #[json_object_key] attribute",
+                field_name.field_name
+            ),
+        };
+
+        sql.push(crate::sql::SelectFieldValue::GroupByField {
+            field_name,
+            statement: format!(
+                "json_group_object(cast({} as text),{})",
+                key_column, field_name.db_column_name
+            )
+            .into(),
+        });
+    }
+}
+
+impl<'s, K: DeserializeOwned + Eq + Hash, V: DeserializeOwned>
+    FromDbRow<'s, GroupByJsonObject<K, V>> for GroupByJsonObject<K, V>
+{
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<GroupByJsonObject<K, V>, DecodeError> {
+        let value: String = row.try_get(column_name.db_column_name)?;
+        let items = crate::json_column::parse_json(column_name.db_column_name, value.as_str())?;
+        Ok(GroupByJsonObject(items))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<GroupByJsonObject<K, V>>, DecodeError> {
+        let value: Option<String> = row.try_get(column_name.db_column_name)?;
+
+        match value {
+            Some(value) => {
+                let items =
+                    crate::json_column::parse_json(column_name.db_column_name, value.as_str())?;
+                Ok(Some(GroupByJsonObject(items)))
+            }
+            None => Ok(None),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use async_sqlite::rusqlite::types::FromSql;
 use crate::{
     sql::SelectBuilder,
     sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
-    GroupByFieldType, SqlValueMetadata,
+    DecodeError, GroupByFieldType, SqlValueMetadata,
 };
 
 pub struct GroupByMin<T: Send + Sync + 'static>(T);
@@ -32,26 +32,28 @@ impl<'s, T: GroupByFieldType + Send + Sync + 'static> SelectValueProvider for Gr
 
         sql.push(crate::sql::SelectFieldValue::GroupByField {
             field_name,
-            statement: format!("cast(MIN({}) as {})", field_name.db_column_name, sql_type).into(),
+            statement: crate::render_group_by_statement("MIN", field_name.db_column_name, sql_type)
+                .into(),
         });
     }
 }
 
 impl<'s, T: Copy + FromSql + Send + Sync + 'static> FromDbRow<'s, GroupByMin<T>> for GroupByMin<T> {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> GroupByMin<T> {
-        GroupByMin(row.get(column_name.db_column_name))
+    ) -> Result<GroupByMin<T>, DecodeError> {
+        let value: T = row.try_get(column_name.db_column_name)?;
+        Ok(GroupByMin(value))
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<GroupByMin<T>> {
-        let result: Option<T> = row.get(column_name.db_column_name);
-        Some(GroupByMin(result?))
+    ) -> Result<Option<GroupByMin<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(GroupByMin))
     }
 }
@@ -0,0 +1,72 @@
+use async_sqlite::rusqlite::types::Value;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DateTimeStorage, DecodeError, SqlValueMetadata, TimeBucket,
+};
+
+pub struct GroupByTimeBucket(DateTimeAsMicroseconds);
+
+impl GroupByTimeBucket {
+    pub fn get_value(&self) -> DateTimeAsMicroseconds {
+        self.0
+    }
+}
+
+fn get_time_bucket(metadata: &Option<SqlValueMetadata>) -> TimeBucket {
+    let Some(time_bucket) = metadata.as_ref().and_then(|metadata| metadata.time_bucket) else {
+        panic!("GroupByTimeBucket requires #[time_bucket] attribute");
+    };
+
+    match TimeBucket::parse(time_bucket) {
+        Some(result) => result,
+        None => panic!("Invalid time_bucket for GroupByTimeBucket: {}", time_bucket),
+    }
+}
+
+impl SelectValueProvider for GroupByTimeBucket {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let storage = DateTimeStorage::from_metadata(metadata);
+
+        sql.push(crate::sql::SelectFieldValue::GroupByKey {
+            field_name,
+            statement: get_time_bucket(metadata)
+                .render_sql(field_name.db_column_name, storage)
+                .into(),
+        });
+    }
+}
+
+impl<'s> FromDbRow<'s, GroupByTimeBucket> for GroupByTimeBucket {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<GroupByTimeBucket, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(column_name.db_column_name, "GroupByTimeBucket")),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<GroupByTimeBucket>, DecodeError> {
+        let value: Value = row.try_get(column_name.db_column_name)?;
+
+        let storage = TimeBucket::get_result_storage(DateTimeStorage::from_metadata(metadata));
+
+        match storage.try_decode(value) {
+            Ok(result) => Ok(result.map(DateTimeAsMicroseconds::new).map(GroupByTimeBucket)),
+            Err(err) => Err(DecodeError::invalid_value(column_name.db_column_name, err)),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct GroupByTotal(f64);
+
+impl GroupByTotal {
+    pub fn get_value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl SelectValueProvider for GroupByTotal {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(crate::sql::SelectFieldValue::GroupByField {
+            field_name,
+            statement: format!("TOTAL({})", field_name.db_column_name).into(),
+        });
+    }
+}
+
+impl<'s> FromDbRow<'s, GroupByTotal> for GroupByTotal {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<GroupByTotal, DecodeError> {
+        let value: f64 = row.try_get(column_name.db_column_name)?;
+        Ok(GroupByTotal(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<GroupByTotal>, DecodeError> {
+        let result: Option<f64> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(GroupByTotal))
+    }
+}
@@ -10,3 +10,19 @@ mod group_by_min;
 pub use group_by_min::*;
 mod group_by_field_type;
 pub use group_by_field_type::*;
+mod aggregate_field_type;
+pub use aggregate_field_type::*;
+mod group_by_concat;
+pub use group_by_concat::*;
+mod group_by_total;
+pub use group_by_total::*;
+mod group_by_count_distinct;
+pub use group_by_count_distinct::*;
+mod group_by_json_array;
+pub use group_by_json_array::*;
+mod group_by_json_object;
+pub use group_by_json_object::*;
+mod time_bucket;
+pub use time_bucket::*;
+mod group_by_time_bucket;
+pub use group_by_time_bucket::*;
@@ -0,0 +1,134 @@
+use crate::DateTimeStorage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBucket {
+    Minute,
+    Hour,
+    Day,
+    Seconds(i64),
+}
+
+impl TimeBucket {
+    pub fn parse(src: &str) -> Option<Self> {
+        match src {
+            "minute" => return Some(Self::Minute),
+            "hour" => return Some(Self::Hour),
+            "day" => return Some(Self::Day),
+            _ => {}
+        }
+
+        let (value, multiplier) = if let Some(value) = src.strip_suffix('s') {
+            (value, 1)
+        } else if let Some(value) = src.strip_suffix('m') {
+            (value, 60)
+        } else if let Some(value) = src.strip_suffix('h') {
+            (value, 3_600)
+        } else if let Some(value) = src.strip_suffix('d') {
+            (value, 86_400)
+        } else {
+            return None;
+        };
+
+        let value: i64 = value.parse().ok()?;
+
+        if value <= 0 {
+            return None;
+        }
+
+        let seconds = value.checked_mul(multiplier)?;
+
+        // Buckets are rendered in microseconds for bigint storage.
+        seconds.checked_mul(1_000_000)?;
+
+        Some(Self::Seconds(seconds))
+    }
+
+    pub fn get_seconds(&self) -> i64 {
+        match self {
+            Self::Minute => 60,
+            Self::Hour => 3_600,
+            Self::Day => 86_400,
+            Self::Seconds(seconds) => *seconds,
+        }
+    }
+
+    pub fn render_sql(&self, column_name: &str, storage: DateTimeStorage) -> String {
+        let seconds = self.get_seconds();
+
+        match storage {
+            DateTimeStorage::UnixMicroseconds => {
+                render_integer_bucket(column_name, seconds * 1_000_000)
+            }
+            DateTimeStorage::UnixMilliseconds => {
+                render_integer_bucket(column_name, seconds * 1_000)
+            }
+            DateTimeStorage::UnixSeconds => render_integer_bucket(column_name, seconds),
+            DateTimeStorage::IsoText { .. } => match self {
+                Self::Minute => format!("strftime('%Y-%m-%dT%H:%M:00',{})", column_name),
+                Self::Hour => format!("strftime('%Y-%m-%dT%H:00:00',{})", column_name),
+                Self::Day => format!("strftime('%Y-%m-%dT00:00:00',{})", column_name),
+                Self::Seconds(_) => format!(
+                    "strftime('%Y-%m-%dT%H:%M:%S',{},'unixepoch')",
+                    render_integer_bucket(
+                        format!("CAST(strftime('%s',{}) AS INTEGER)", column_name).as_str(),
+                        seconds
+                    )
+                ),
+            },
+            DateTimeStorage::JulianDay => format!(
+                "{}*1000000",
+                render_integer_bucket(
+                    format!("CAST(round(({}-2440587.5)*86400) AS INTEGER)", column_name).as_str(),
+                    seconds
+                )
+            ),
+        }
+    }
+
+    // Julian days are bucketed into unix microseconds so the bucket start stays exact.
+    pub fn get_result_storage(storage: DateTimeStorage) -> DateTimeStorage {
+        match storage {
+            DateTimeStorage::JulianDay => DateTimeStorage::UnixMicroseconds,
+            _ => storage,
+        }
+    }
+}
+
+// SQLite integer division truncates towards zero, so values before 1970 are floored explicitly.
+fn render_integer_bucket(column_name: &str, width: i64) -> String {
+    format!(
+        "({}-((({}%{})+{})%{}))",
+        column_name, column_name, width, width, width
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(TimeBucket::parse("hour"), Some(TimeBucket::Hour));
+        assert_eq!(TimeBucket::parse("15m"), Some(TimeBucket::Seconds(900)));
+        assert_eq!(TimeBucket::parse("2d"), Some(TimeBucket::Seconds(172_800)));
+        assert_eq!(TimeBucket::parse("0s"), None);
+        assert_eq!(TimeBucket::parse("week"), None);
+        assert_eq!(TimeBucket::parse("5é"), None);
+        assert_eq!(TimeBucket::parse("m"), None);
+        assert_eq!(TimeBucket::parse("9223372036854775807d"), None);
+        assert_eq!(TimeBucket::parse("9223372036855s"), None);
+    }
+
+    #[test]
+    fn test_render_sql() {
+        assert_eq!(
+            TimeBucket::Minute.render_sql("moment", DateTimeStorage::UnixMicroseconds),
+            "(moment-(((moment%60000000)+60000000)%60000000))"
+        );
+
+        assert_eq!(
+            TimeBucket::Hour.render_sql("moment", DateTimeStorage::IsoText { precision: 6 }),
+            "strftime('%Y-%m-%dT%H:00:00',moment)"
+        );
+    }
+}
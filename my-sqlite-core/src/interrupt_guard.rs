@@ -0,0 +1,66 @@
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc,
+};
+
+use async_sqlite::rusqlite::{ffi, InterruptHandle};
+
+const QUEUED: u8 = 0;
+const RUNNING: u8 = 1;
+const FINISHED: u8 = 2;
+const CANCELLED: u8 = 3;
+
+#[derive(Clone)]
+pub(crate) struct SqlExecutionState(Arc<AtomicU8>);
+
+impl SqlExecutionState {
+    pub fn start(&self) -> Result<(), async_sqlite::rusqlite::Error> {
+        match self
+            .0
+            .compare_exchange(QUEUED, RUNNING, Ordering::SeqCst, Ordering::SeqCst)
+        {
+            Ok(_) => Ok(()),
+            Err(_) => Err(async_sqlite::rusqlite::Error::SqliteFailure(
+                ffi::Error::new(ffi::SQLITE_INTERRUPT),
+                Some("Operation was cancelled before it started".to_string()),
+            )),
+        }
+    }
+
+    pub fn finish(&self) {
+        self.0.store(FINISHED, Ordering::SeqCst);
+    }
+}
+
+pub(crate) struct InterruptGuard {
+    handle: Arc<InterruptHandle>,
+    state: SqlExecutionState,
+}
+
+impl InterruptGuard {
+    pub fn new(handle: Arc<InterruptHandle>) -> Self {
+        Self {
+            handle,
+            state: SqlExecutionState(Arc::new(AtomicU8::new(QUEUED))),
+        }
+    }
+
+    pub fn get_state(&self) -> SqlExecutionState {
+        self.state.clone()
+    }
+}
+
+impl Drop for InterruptGuard {
+    fn drop(&mut self) {
+        let result = self.state.0.compare_exchange(
+            QUEUED,
+            CANCELLED,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        );
+
+        if result == Err(RUNNING) {
+            self.handle.interrupt();
+        }
+    }
+}
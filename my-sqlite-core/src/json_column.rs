@@ -0,0 +1,163 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    sql::{SelectBuilder, SelectFieldValue, SqlUpdateValue, SqlValues},
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    sql_update::SqlUpdateValueProvider,
+    table_schema::{SqlTypeProvider, TableColumnType},
+    DecodeError, RenderFullWhereCondition, SqlValueMetadata, SqlWhereValueProvider,
+};
+
+pub struct JsonColumn<T>(pub T);
+
+impl<T> JsonColumn<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+pub struct JsonColumnRef<'s, T>(pub &'s T);
+
+pub trait JsonValueProvider {
+    fn push_json_param(&self, params: &mut SqlValues) -> usize;
+}
+
+impl<T: Serialize> JsonValueProvider for T {
+    fn push_json_param(&self, params: &mut SqlValues) -> usize {
+        params.push_json(self)
+    }
+}
+
+fn fill_json_where_value<T: Serialize>(
+    value: &T,
+    full_where_condition: Option<RenderFullWhereCondition>,
+    sql: &mut String,
+    params: &mut SqlValues,
+    metadata: &Option<SqlValueMetadata>,
+) -> bool {
+    if let Some(full_where_condition) = full_where_condition {
+        full_where_condition.render_param_name(sql, "=", metadata);
+    }
+
+    let index = value.push_json_param(params);
+    sql.push('$');
+    sql.push_str(index.to_string().as_str());
+
+    true
+}
+
+pub(crate) fn parse_json<T: DeserializeOwned>(
+    column_name: &str,
+    value: &str,
+) -> Result<T, DecodeError> {
+    match serde_json::from_str(value) {
+        Ok(result) => Ok(result),
+        Err(err) => Err(DecodeError::json(column_name, err)),
+    }
+}
+
+impl<T> SqlTypeProvider for JsonColumn<T> {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        if let Some(metadata) = &metadata {
+            if metadata.sql_type == Some("jsonb") {
+                return TableColumnType::Jsonb;
+            }
+        }
+
+        TableColumnType::Json
+    }
+}
+
+impl<T> SelectValueProvider for JsonColumn<T> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Json(field_name));
+    }
+}
+
+impl<'s, T: DeserializeOwned> FromDbRow<'s, T> for JsonColumn<T> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<T, DecodeError> {
+        let mut db_column_name = String::new();
+        crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
+
+        let value: String = row.try_get(db_column_name.as_str())?;
+        parse_json(column_name.db_column_name, value.as_str())
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<T>, DecodeError> {
+        let mut db_column_name = String::new();
+        crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
+
+        let value: Option<String> = row.try_get(db_column_name.as_str())?;
+
+        match value {
+            Some(value) => Ok(Some(parse_json(column_name.db_column_name, value.as_str())?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T: Serialize> SqlUpdateValueProvider for JsonColumn<T> {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        let index = self.0.push_json_param(params);
+        SqlUpdateValue::Json(index)
+    }
+}
+
+impl<T: Serialize> SqlWhereValueProvider for JsonColumn<T> {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        fill_json_where_value(&self.0, full_where_condition, sql, params, metadata)
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
+
+impl<'s, T: Serialize> SqlUpdateValueProvider for JsonColumnRef<'s, T> {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        let index = self.0.push_json_param(params);
+        SqlUpdateValue::Json(index)
+    }
+}
+
+impl<'s, T: Serialize> SqlWhereValueProvider for JsonColumnRef<'s, T> {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        fill_json_where_value(self.0, full_where_condition, sql, params, metadata)
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
@@ -3,6 +3,7 @@ pub mod sql_insert;
 pub mod sql_update;
 mod sql_value;
 pub mod sql_where;
+pub mod sql_having;
 pub use sql_value::*;
 mod column_name;
 pub use column_name::*;
@@ -21,12 +22,45 @@ mod sql_lite_connection_builder;
 pub mod table_schema;
 pub use sql_lite_connection_builder::*;
 mod crate_table;
+mod external_types;
 mod error;
 pub use error::*;
+mod decode_error;
+pub use decode_error::*;
+mod decoded_rows;
+pub use decoded_rows::*;
 mod group_by_fields;
 pub use group_by_fields::*;
+mod window_fields;
+pub use window_fields::*;
 mod count_result;
 pub use count_result::*;
+mod json_column;
+pub use json_column::*;
+mod logs;
+pub use logs::*;
+mod query_metrics;
+pub use query_metrics::*;
+mod query_plan;
+pub use query_plan::*;
+mod interrupt_guard;
+pub(crate) use interrupt_guard::*;
+mod select_sql_cache;
+pub(crate) use select_sql_cache::*;
+mod sql_request;
+pub(crate) use sql_request::*;
+#[cfg(feature = "with-logs-and-telemetry")]
+mod telemetry;
+#[cfg(feature = "with-logs-and-telemetry")]
+pub use telemetry::*;
 
 mod sqlite_query_stream;
 pub use sqlite_query_stream::*;
+mod sql_chunks_reader;
+pub use sql_chunks_reader::*;
+mod page;
+pub use page::*;
+mod paged_result;
+pub use paged_result::*;
+mod page_cursor;
+pub(crate) use page_cursor::*;
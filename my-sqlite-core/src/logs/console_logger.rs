@@ -0,0 +1,32 @@
+use super::{SqlLiteLogger, SqlLogEvent};
+
+pub struct ConsoleLogger;
+
+impl SqlLiteLogger for ConsoleLogger {
+    fn log(&self, event: &SqlLogEvent) {
+        if let Some(err) = event.error {
+            println!(
+                "Err: {}. Operation: {}. Duration: {:?}. Sql: {}",
+                err, event.operation, event.duration, event.sql
+            );
+            return;
+        }
+
+        if let Some(query_plan) = event.query_plan {
+            println!(
+                "Slow query: {}. Operation: {}. Params: {:?}. Duration: {:?}. Plan: {}",
+                event.sql,
+                event.operation,
+                event.params.unwrap_or_default(),
+                event.duration,
+                query_plan
+            );
+            return;
+        }
+
+        println!(
+            "Sql: {}. Operation: {}. Params: {}. Duration: {:?}. Rows: {:?}",
+            event.sql, event.operation, event.params_count, event.duration, event.rows
+        );
+    }
+}
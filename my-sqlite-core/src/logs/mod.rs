@@ -0,0 +1,14 @@
+mod sql_operation;
+pub use sql_operation::*;
+mod sql_log_level;
+pub use sql_log_level::*;
+mod sql_log_event;
+pub use sql_log_event::*;
+mod sql_lite_logger;
+pub use sql_lite_logger::*;
+mod console_logger;
+pub use console_logger::*;
+#[cfg(feature = "with-tracing")]
+mod tracing_logger;
+#[cfg(feature = "with-tracing")]
+pub use tracing_logger::*;
@@ -0,0 +1,5 @@
+use super::SqlLogEvent;
+
+pub trait SqlLiteLogger: Send + Sync {
+    fn log(&self, event: &SqlLogEvent);
+}
@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use crate::{QueryPlan, SqlLiteError};
+
+use super::{SqlLogLevel, SqlOperation};
+
+#[derive(Debug)]
+pub struct SqlLogEvent<'s> {
+    pub level: SqlLogLevel,
+    pub operation: SqlOperation,
+    pub table_name: &'s str,
+    pub sql: &'s str,
+    pub params_count: usize,
+    pub duration: Duration,
+    pub rows: Option<usize>,
+    pub error: Option<&'s SqlLiteError>,
+    pub params: Option<&'s [String]>,
+    pub query_plan: Option<&'s QueryPlan>,
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SqlLogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl SqlLogLevel {
+    pub fn is_enabled(&self, level: SqlLogLevel) -> bool {
+        level != SqlLogLevel::Off && level <= *self
+    }
+}
@@ -0,0 +1,69 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlOperation {
+    CreateTable,
+    Insert,
+    InsertIfNotExists,
+    InsertOrUpdate,
+    Update,
+    BulkInsert,
+    BulkInsertIfNotExists,
+    BulkInsertOrUpdate,
+    Select,
+    SelectSingle,
+    SelectStream,
+    SelectChunk,
+    SelectPage,
+    Count,
+    Exists,
+    Aggregate,
+    Explain,
+    Delete,
+    Close,
+}
+
+impl SqlOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreateTable => "create_table",
+            Self::Insert => "insert",
+            Self::InsertIfNotExists => "insert_if_not_exists",
+            Self::InsertOrUpdate => "insert_or_update",
+            Self::Update => "update",
+            Self::BulkInsert => "bulk_insert",
+            Self::BulkInsertIfNotExists => "bulk_insert_if_not_exists",
+            Self::BulkInsertOrUpdate => "bulk_insert_or_update",
+            Self::Select => "select",
+            Self::SelectSingle => "select_single",
+            Self::SelectStream => "select_stream",
+            Self::SelectChunk => "select_chunk",
+            Self::SelectPage => "select_page",
+            Self::Count => "count",
+            Self::Exists => "exists",
+            Self::Aggregate => "aggregate",
+            Self::Explain => "explain",
+            Self::Delete => "delete",
+            Self::Close => "close",
+        }
+    }
+
+    pub fn is_read(&self) -> bool {
+        match self {
+            Self::Select
+            | Self::SelectSingle
+            | Self::SelectStream
+            | Self::SelectChunk
+            | Self::SelectPage
+            | Self::Count
+            | Self::Exists
+            | Self::Aggregate
+            | Self::Explain => true,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for SqlOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,33 @@
+use super::{SqlLiteLogger, SqlLogEvent, SqlLogLevel};
+
+pub struct TracingLogger;
+
+macro_rules! trace_sql_event {
+    ($macro:ident, $event:expr) => {
+        tracing::$macro!(
+            target: "my_sqlite",
+            operation = $event.operation.as_str(),
+            table_name = $event.table_name,
+            sql = $event.sql,
+            params_count = $event.params_count,
+            duration_us = $event.duration.as_micros() as u64,
+            rows = ?$event.rows,
+            error = ?$event.error.map(|err| err.to_string()),
+            params = ?$event.params,
+            query_plan = ?$event.query_plan.map(|plan| plan.to_string()),
+        )
+    };
+}
+
+impl SqlLiteLogger for TracingLogger {
+    fn log(&self, event: &SqlLogEvent) {
+        match event.level {
+            SqlLogLevel::Off => {}
+            SqlLogLevel::Error => trace_sql_event!(error, event),
+            SqlLogLevel::Warn => trace_sql_event!(warn, event),
+            SqlLogLevel::Info => trace_sql_event!(info, event),
+            SqlLogLevel::Debug => trace_sql_event!(debug, event),
+            SqlLogLevel::Trace => trace_sql_event!(trace, event),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub struct Page<TEntity> {
+    pub items: Vec<TEntity>,
+    pub next_cursor: Option<String>,
+}
+
+impl<TEntity> Page<TEntity> {
+    pub fn has_next_page(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
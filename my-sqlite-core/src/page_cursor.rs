@@ -0,0 +1,358 @@
+use async_sqlite::rusqlite::{
+    types::{Type, Value},
+    Row,
+};
+
+use crate::{
+    sql::{SelectBuilder, SqlData, SqlValues},
+    sql_select::SelectEntity,
+    sql_where::SqlWhereModel,
+    table_schema::TableColumn,
+    ColumnName, SqlLiteError,
+};
+
+pub(crate) struct OrderByColumn {
+    pub name: String,
+    pub desc: bool,
+}
+
+pub(crate) struct PageCursor {
+    columns: Vec<OrderByColumn>,
+}
+
+impl PageCursor {
+    pub fn from_order_by(order_by: &str) -> Self {
+        let mut order_by = order_by.trim();
+
+        if let Some(prefix) = order_by.get(..8) {
+            if prefix.eq_ignore_ascii_case("ORDER BY") {
+                order_by = &order_by[8..];
+            }
+        }
+
+        let mut columns = Vec::new();
+
+        for itm in order_by.split(',') {
+            let mut parts = itm.split_whitespace();
+
+            let Some(name) = parts.next() else {
+                continue;
+            };
+
+            let desc = match parts.next() {
+                Some(direction) => direction.eq_ignore_ascii_case("DESC"),
+                None => false,
+            };
+
+            columns.push(OrderByColumn {
+                name: name.to_string(),
+                desc,
+            });
+        }
+
+        Self { columns }
+    }
+
+    // Rows with equal ORDER BY values would be skipped or repeated between pages,
+    // so primary key columns are appended to make the order unique.
+    pub fn append_primary_key(&mut self, primary_key_columns: &[ColumnName]) {
+        for column in primary_key_columns {
+            if self.has_column(column.get_name()) {
+                continue;
+            }
+
+            let mut name = String::new();
+            column.push_name(&mut name);
+
+            self.columns.push(OrderByColumn { name, desc: false });
+        }
+    }
+
+    pub fn check_not_nullable(&self, table_columns: &[TableColumn]) -> Result<(), SqlLiteError> {
+        for table_column in table_columns {
+            if table_column.is_nullable && self.has_column(table_column.name.get_name()) {
+                return Err(SqlLiteError::InvalidArgument {
+                    message: format!(
+                        "query_page_by_cursor does not support nullable order_by column {}",
+                        table_column.name.get_name()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn has_column(&self, name: &str) -> bool {
+        self.columns
+            .iter()
+            .any(|column| column.name.trim_matches('"') == name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn build_select_sql<TEntity: SelectEntity, TWhereModel: SqlWhereModel>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        last_key: Option<&[Value]>,
+        limit: usize,
+    ) -> SqlData {
+        let select_builder = SelectBuilder::from_select_model::<TEntity>();
+
+        let mut sql = String::new();
+        let mut values = SqlValues::new();
+
+        sql.push_str("SELECT ");
+        select_builder.fill_select_fields(&mut sql);
+
+        for column in self.columns.iter() {
+            sql.push(',');
+            sql.push_str(column.name.as_str());
+        }
+
+        sql.push_str(" FROM ");
+        sql.push_str(table_name);
+
+        let mut has_where = false;
+
+        if let Some(where_model) = where_model {
+            if where_model.has_conditions() {
+                sql.push_str(" WHERE (");
+                where_model.fill_where_component(&mut sql, &mut values);
+                sql.push(')');
+                has_where = true;
+            }
+        }
+
+        if let Some(last_key) = last_key {
+            if has_where {
+                sql.push_str(" AND ");
+            } else {
+                sql.push_str(" WHERE ");
+            }
+
+            self.fill_keyset_condition(&mut sql, &mut values, last_key);
+        }
+
+        sql.push_str(" ORDER BY ");
+
+        for (no, column) in self.columns.iter().enumerate() {
+            if no > 0 {
+                sql.push(',');
+            }
+
+            sql.push_str(column.name.as_str());
+
+            if column.desc {
+                sql.push_str(" DESC");
+            }
+        }
+
+        sql.push_str(" LIMIT ");
+        sql.push_str(limit.to_string().as_str());
+
+        SqlData::new(sql, values)
+    }
+
+    // (a,b) after (x,y) is rendered as (a>x OR (a=x AND b>y)) so every column can
+    // have its own direction.
+    fn fill_keyset_condition(&self, sql: &mut String, values: &mut SqlValues, last_key: &[Value]) {
+        let indexes: Vec<usize> = last_key
+            .iter()
+            .map(|value| push_key_value(values, value))
+            .collect();
+
+        sql.push('(');
+
+        for (no, column) in self.columns.iter().enumerate() {
+            if no > 0 {
+                sql.push_str(" OR ");
+            }
+
+            sql.push('(');
+
+            for (prev_no, prev_column) in self.columns[..no].iter().enumerate() {
+                sql.push_str(prev_column.name.as_str());
+                sql.push_str("=$");
+                sql.push_str(indexes[prev_no].to_string().as_str());
+                sql.push_str(" AND ");
+            }
+
+            sql.push_str(column.name.as_str());
+            sql.push_str(if column.desc { "<$" } else { ">$" });
+            sql.push_str(indexes[no].to_string().as_str());
+
+            sql.push(')');
+        }
+
+        sql.push(')');
+    }
+
+    pub fn encode(values: &[Value]) -> String {
+        let items: Vec<String> = values
+            .iter()
+            .map(|value| match value {
+                Value::Integer(value) => format!("i:{}", value),
+                Value::Real(value) => format!("r:{}", value),
+                Value::Text(value) => format!("t:{}", value),
+                Value::Blob(value) => format!("b:{}", to_hex(value)),
+                Value::Null => "n:".to_string(),
+            })
+            .collect();
+
+        to_hex(serde_json::to_string(&items).unwrap().as_bytes())
+    }
+
+    pub fn decode(&self, cursor: &str) -> Result<Vec<Value>, SqlLiteError> {
+        let invalid_cursor = |message: &str| SqlLiteError::InvalidCursor {
+            message: message.to_string(),
+        };
+
+        let json = from_hex(cursor).ok_or_else(|| invalid_cursor("cursor is not a hex string"))?;
+
+        let items: Vec<String> = serde_json::from_slice(json.as_slice())
+            .map_err(|_| invalid_cursor("cursor is not a json array"))?;
+
+        if items.len() != self.columns.len() {
+            return Err(invalid_cursor("cursor does not match ORDER BY columns"));
+        }
+
+        let mut result = Vec::with_capacity(items.len());
+
+        for itm in items {
+            let value = match itm.split_once(':') {
+                Some(("i", value)) => value.parse().ok().map(Value::Integer),
+                Some(("r", value)) => value.parse().ok().map(Value::Real),
+                Some(("t", value)) => Some(Value::Text(value.to_string())),
+                Some(("b", value)) => from_hex(value).map(Value::Blob),
+                _ => None,
+            };
+
+            match value {
+                Some(value) => result.push(value),
+                None => return Err(invalid_cursor("cursor contains invalid value")),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub(crate) fn read_row_key(
+    row: &Row,
+    first_index: usize,
+    columns_count: usize,
+) -> Result<Vec<Value>, async_sqlite::rusqlite::Error> {
+    let mut result = Vec::with_capacity(columns_count);
+
+    for index in first_index..first_index + columns_count {
+        let value: Value = row.get(index)?;
+
+        if value == Value::Null {
+            return Err(async_sqlite::rusqlite::Error::InvalidColumnType(
+                index,
+                "key".to_string(),
+                Type::Null,
+            ));
+        }
+
+        result.push(value);
+    }
+
+    Ok(result)
+}
+
+pub(crate) fn push_key_value(values: &mut SqlValues, value: &Value) -> usize {
+    match value {
+        Value::Integer(value) => values.push_i64(*value),
+        Value::Real(value) => values.push_f64(*value),
+        Value::Text(value) => values.push(value.clone().into()),
+        Value::Blob(value) => values.push_blob(value.clone()),
+        Value::Null => panic!("Key value can not be null"),
+    }
+}
+
+fn to_hex(src: &[u8]) -> String {
+    let mut result = String::with_capacity(src.len() * 2);
+
+    for b in src {
+        result.push_str(format!("{:02x}", b).as_str());
+    }
+
+    result
+}
+
+fn from_hex(src: &str) -> Option<Vec<u8>> {
+    if src.len() % 2 != 0 {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(src.len() / 2);
+
+    for i in (0..src.len()).step_by(2) {
+        result.push(u8::from_str_radix(src.get(i..i + 2)?, 16).ok()?);
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_order_by() {
+        let cursor = PageCursor::from_order_by(" ORDER BY created DESC,id DESC");
+
+        assert_eq!(cursor.len(), 2);
+        assert_eq!(cursor.columns[0].name, "created");
+        assert!(cursor.columns[0].desc);
+        assert_eq!(cursor.columns[1].name, "id");
+        assert!(cursor.columns[1].desc);
+    }
+
+    #[test]
+    fn test_append_primary_key() {
+        let mut cursor = PageCursor::from_order_by(" ORDER BY created DESC,id DESC");
+        cursor.append_primary_key(&["id".into(), "tenant".into()]);
+
+        assert_eq!(cursor.len(), 3);
+        assert_eq!(cursor.columns[2].name, "tenant");
+        assert!(!cursor.columns[2].desc);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let cursor = PageCursor::from_order_by(" ORDER BY a,b,c");
+
+        let values = vec![
+            Value::Integer(-5),
+            Value::Real(1.25),
+            Value::Text("a:b,c".to_string()),
+        ];
+
+        let encoded = PageCursor::encode(values.as_slice());
+
+        assert_eq!(cursor.decode(encoded.as_str()).unwrap(), values);
+        assert!(cursor.decode("zz").is_err());
+    }
+
+    #[test]
+    fn test_keyset_condition() {
+        let cursor = PageCursor::from_order_by(" ORDER BY a DESC,b");
+
+        let mut sql = String::new();
+        let mut values = SqlValues::new();
+
+        cursor.fill_keyset_condition(
+            &mut sql,
+            &mut values,
+            &[Value::Integer(10), Value::Integer(20)],
+        );
+
+        assert_eq!(sql, "((a<$1) OR (a=$1 AND b>$2))");
+    }
+}
@@ -0,0 +1,17 @@
+pub struct PagedResult<TEntity> {
+    pub items: Vec<TEntity>,
+    pub total: u64,
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl<TEntity> PagedResult<TEntity> {
+    pub fn get_pages_count(&self) -> u64 {
+        let page_size = self.page_size as u64;
+        (self.total + page_size - 1) / page_size
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        (self.page as u64) < self.get_pages_count()
+    }
+}
@@ -0,0 +1,4 @@
+mod operation_metrics;
+pub use operation_metrics::*;
+mod sql_metrics;
+pub use sql_metrics::*;
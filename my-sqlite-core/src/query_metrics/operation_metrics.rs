@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use crate::SqlOperation;
+
+pub const LATENCY_BUCKETS_MICROS: [u64; 10] = [
+    100, 500, 1_000, 5_000, 10_000, 50_000, 100_000, 500_000, 1_000_000, 5_000_000,
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    pub buckets: [u64; LATENCY_BUCKETS_MICROS.len() + 1],
+    pub sum: Duration,
+    pub count: u64,
+}
+
+impl LatencyHistogram {
+    pub fn observe(&mut self, duration: Duration) {
+        let micros = duration.as_micros() as u64;
+
+        let index = LATENCY_BUCKETS_MICROS
+            .iter()
+            .position(|bound| micros <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MICROS.len());
+
+        self.buckets[index] += 1;
+        self.sum += duration;
+        self.count += 1;
+    }
+
+    pub fn get_average(&self) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+
+        Duration::from_nanos((self.sum.as_nanos() / self.count as u128) as u64)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperationMetrics {
+    pub table_name: String,
+    pub operation: SqlOperation,
+    pub calls: u64,
+    pub errors: u64,
+    pub rows_read: u64,
+    pub rows_written: u64,
+    pub latency: LatencyHistogram,
+    pub wait_time: LatencyHistogram,
+}
+
+impl OperationMetrics {
+    pub fn new(table_name: String, operation: SqlOperation) -> Self {
+        Self {
+            table_name,
+            operation,
+            calls: 0,
+            errors: 0,
+            rows_read: 0,
+            rows_written: 0,
+            latency: LatencyHistogram::default(),
+            wait_time: LatencyHistogram::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_latency_histogram_buckets() {
+        let mut histogram = LatencyHistogram::default();
+
+        histogram.observe(Duration::from_micros(50));
+        histogram.observe(Duration::from_micros(700));
+        histogram.observe(Duration::from_secs(10));
+
+        assert_eq!(1, histogram.buckets[0]);
+        assert_eq!(1, histogram.buckets[2]);
+        assert_eq!(1, histogram.buckets[LATENCY_BUCKETS_MICROS.len()]);
+        assert_eq!(3, histogram.count);
+    }
+
+    #[test]
+    fn test_latency_histogram_average_with_large_count() {
+        let mut histogram = LatencyHistogram::default();
+        histogram.sum = Duration::from_secs(10_000_000);
+        histogram.count = u32::MAX as u64 + 1;
+
+        assert_eq!(
+            Duration::from_nanos(10_000_000_000_000_000 / (u32::MAX as u64 + 1)),
+            histogram.get_average()
+        );
+    }
+}
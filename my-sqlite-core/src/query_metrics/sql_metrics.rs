@@ -0,0 +1,185 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::SqlOperation;
+
+use super::OperationMetrics;
+
+#[derive(Debug, Clone)]
+pub struct SqlMetricsSnapshot {
+    pub operations: Vec<OperationMetrics>,
+    pub queue_depth: usize,
+    pub max_queue_depth: usize,
+}
+
+impl SqlMetricsSnapshot {
+    pub fn get(&self, table_name: &str, operation: SqlOperation) -> Option<&OperationMetrics> {
+        self.operations
+            .iter()
+            .find(|itm| itm.table_name == table_name && itm.operation == operation)
+    }
+}
+
+pub(crate) struct SqlQueueGuard<'s> {
+    metrics: &'s SqlMetrics,
+}
+
+impl<'s> Drop for SqlQueueGuard<'s> {
+    fn drop(&mut self) {
+        self.metrics.leave();
+    }
+}
+
+// Time a statement spent queued before the connection thread picked it up.
+// A statement that never started (timed out or cancelled while queued) waited
+// for its whole duration.
+pub(crate) struct SqlWaitTime(AtomicU64);
+
+impl SqlWaitTime {
+    const NOT_STARTED: u64 = u64::MAX;
+
+    pub fn new() -> Self {
+        Self(AtomicU64::new(Self::NOT_STARTED))
+    }
+
+    pub fn set_started(&self, queued_at: Instant) {
+        self.0
+            .store(queued_at.elapsed().as_micros() as u64, Ordering::Relaxed);
+    }
+
+    pub fn get(&self, duration: Duration) -> Duration {
+        match self.0.load(Ordering::Relaxed) {
+            Self::NOT_STARTED => duration,
+            wait_time => Duration::from_micros(wait_time),
+        }
+    }
+}
+
+pub(crate) struct SqlMetricsRecord<'s> {
+    pub table_name: &'s str,
+    pub operation: SqlOperation,
+    pub duration: Duration,
+    pub wait_time: Duration,
+    pub rows: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct SqlMetrics {
+    operations: Mutex<HashMap<(String, SqlOperation), OperationMetrics>>,
+    queue_depth: AtomicUsize,
+    max_queue_depth: AtomicUsize,
+}
+
+impl SqlMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn enter(&self) -> SqlQueueGuard<'_> {
+        let depth = self.queue_depth.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_queue_depth.fetch_max(depth, Ordering::SeqCst);
+
+        #[cfg(feature = "with-metrics")]
+        metrics::gauge!("sqlite_queue_depth").set(depth as f64);
+
+        SqlQueueGuard { metrics: self }
+    }
+
+    fn leave(&self) {
+        let depth = self.queue_depth.fetch_sub(1, Ordering::SeqCst) - 1;
+
+        #[cfg(feature = "with-metrics")]
+        metrics::gauge!("sqlite_queue_depth").set(depth as f64);
+
+        #[cfg(not(feature = "with-metrics"))]
+        let _ = depth;
+    }
+
+    pub(crate) fn record(&self, record: SqlMetricsRecord) {
+        let is_read = record.operation.is_read();
+
+        {
+            let mut operations = self.operations.lock().unwrap();
+
+            let itm = operations
+                .entry((record.table_name.to_string(), record.operation))
+                .or_insert_with(|| {
+                    OperationMetrics::new(record.table_name.to_string(), record.operation)
+                });
+
+            itm.calls += 1;
+            itm.latency.observe(record.duration);
+            itm.wait_time.observe(record.wait_time);
+
+            match record.rows {
+                Some(rows) => {
+                    if is_read {
+                        itm.rows_read += rows as u64;
+                    } else {
+                        itm.rows_written += rows as u64;
+                    }
+                }
+                None => itm.errors += 1,
+            }
+        }
+
+        #[cfg(feature = "with-metrics")]
+        {
+            let labels = vec![
+                metrics::Label::new("table", record.table_name.to_string()),
+                metrics::Label::new("operation", record.operation.as_str()),
+            ];
+
+            metrics::counter!("sqlite_requests_total", labels.clone()).increment(1);
+
+            if record.rows.is_none() {
+                metrics::counter!("sqlite_request_errors_total", labels.clone()).increment(1);
+            }
+
+            metrics::histogram!("sqlite_request_duration_seconds", labels.clone())
+                .record(record.duration.as_secs_f64());
+
+            metrics::histogram!("sqlite_request_wait_seconds", labels.clone())
+                .record(record.wait_time.as_secs_f64());
+
+            if let Some(rows) = record.rows {
+                if is_read {
+                    metrics::counter!("sqlite_rows_read_total", labels.clone())
+                        .increment(rows as u64);
+                } else {
+                    metrics::counter!("sqlite_rows_written_total", labels.clone())
+                        .increment(rows as u64);
+                }
+            }
+        }
+    }
+
+    pub fn get_snapshot(&self) -> SqlMetricsSnapshot {
+        let mut operations: Vec<OperationMetrics> =
+            self.operations.lock().unwrap().values().cloned().collect();
+
+        operations.sort_by(|a, b| {
+            a.table_name
+                .cmp(&b.table_name)
+                .then_with(|| a.operation.as_str().cmp(b.operation.as_str()))
+        });
+
+        SqlMetricsSnapshot {
+            operations,
+            queue_depth: self.queue_depth.load(Ordering::SeqCst),
+            max_queue_depth: self.max_queue_depth.load(Ordering::SeqCst),
+        }
+    }
+
+    pub fn reset(&self) {
+        self.operations.lock().unwrap().clear();
+        self.max_queue_depth
+            .store(self.queue_depth.load(Ordering::SeqCst), Ordering::SeqCst);
+    }
+}
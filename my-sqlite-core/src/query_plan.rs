@@ -0,0 +1,102 @@
+use async_sqlite::rusqlite::Connection;
+
+use crate::sql::SqlData;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryPlanStep {
+    pub id: i64,
+    pub parent: i64,
+    pub detail: String,
+}
+
+impl QueryPlanStep {
+    pub fn is_full_scan(&self) -> bool {
+        let detail = self.detail.as_str();
+
+        if !detail.starts_with("SCAN ") {
+            return false;
+        }
+
+        if detail.starts_with("SCAN CONSTANT ROW") {
+            return false;
+        }
+
+        !detail.contains(" USING ")
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryPlan {
+    pub steps: Vec<QueryPlanStep>,
+}
+
+impl QueryPlan {
+    pub(crate) fn read(
+        conn: &Connection,
+        sql_data: &SqlData,
+    ) -> Result<Self, async_sqlite::rusqlite::Error> {
+        let sql = format!("EXPLAIN QUERY PLAN {}", sql_data.sql);
+        let mut stmt = conn.prepare(&sql)?;
+
+        let mut rows = stmt.query(sql_data.values.get_params_to_invoke().as_slice())?;
+
+        let mut steps = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            steps.push(QueryPlanStep {
+                id: row.get(0)?,
+                parent: row.get(1)?,
+                detail: row.get(3)?,
+            });
+        }
+
+        Ok(Self { steps })
+    }
+
+    pub fn has_full_scan(&self) -> bool {
+        self.steps.iter().any(|step| step.is_full_scan())
+    }
+
+    pub fn get_full_scans(&self) -> Vec<&QueryPlanStep> {
+        self.steps
+            .iter()
+            .filter(|step| step.is_full_scan())
+            .collect()
+    }
+}
+
+impl std::fmt::Display for QueryPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (no, step) in self.steps.iter().enumerate() {
+            if no > 0 {
+                f.write_str("; ")?;
+            }
+
+            f.write_str(step.detail.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(detail: &str) -> QueryPlanStep {
+        QueryPlanStep {
+            id: 2,
+            parent: 0,
+            detail: detail.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_full_scan_detection() {
+        assert!(step("SCAN TABLE items").is_full_scan());
+        assert!(step("SCAN items").is_full_scan());
+        assert!(!step("SCAN items USING COVERING INDEX ix_name").is_full_scan());
+        assert!(!step("SEARCH items USING INDEX sqlite_autoindex_items_1 (id=?)").is_full_scan());
+        assert!(!step("SCAN CONSTANT ROW").is_full_scan());
+    }
+}
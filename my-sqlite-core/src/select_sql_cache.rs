@@ -0,0 +1,269 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    sql::{SelectBuilder, SqlData, SqlValues},
+    sql_having::{NoneHavingModel, SqlHavingModel},
+    sql_select::SelectEntity,
+    sql_where::SqlWhereModel,
+};
+
+// WHERE is rendered on every call: its text depends on the where model values
+// (ignore_if_none fields, IN lists). Everything around it is cached per entity and table.
+struct CachedSelect {
+    select_prefix: String,
+    count_prefix: Option<String>,
+    order_by_columns: Option<&'static str>,
+    group_by_columns: Option<String>,
+    select_suffix: String,
+}
+
+struct CacheEntry {
+    value: Arc<CachedSelect>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheItems {
+    tick: u64,
+    len: usize,
+    by_type: HashMap<TypeId, HashMap<String, CacheEntry>>,
+}
+
+impl CacheItems {
+    fn get(&mut self, type_id: TypeId, table_name: &str) -> Option<Arc<CachedSelect>> {
+        self.tick += 1;
+        let entry = self.by_type.get_mut(&type_id)?.get_mut(table_name)?;
+        entry.last_used = self.tick;
+        Some(entry.value.clone())
+    }
+
+    fn insert(&mut self, type_id: TypeId, table_name: &str, value: Arc<CachedSelect>) {
+        let entry = CacheEntry {
+            value,
+            last_used: self.tick,
+        };
+
+        if self
+            .by_type
+            .entry(type_id)
+            .or_default()
+            .insert(table_name.to_string(), entry)
+            .is_none()
+        {
+            self.len += 1;
+        }
+    }
+
+    fn remove_least_recently_used(&mut self) {
+        let mut oldest: Option<(TypeId, &str, u64)> = None;
+
+        for (type_id, by_table) in self.by_type.iter() {
+            for (table_name, entry) in by_table.iter() {
+                let is_older = match oldest {
+                    Some((_, _, last_used)) => entry.last_used < last_used,
+                    None => true,
+                };
+
+                if is_older {
+                    oldest = Some((*type_id, table_name.as_str(), entry.last_used));
+                }
+            }
+        }
+
+        let Some((type_id, table_name, _)) = oldest else {
+            return;
+        };
+
+        let table_name = table_name.to_string();
+
+        if let Some(by_table) = self.by_type.get_mut(&type_id) {
+            if by_table.remove(table_name.as_str()).is_some() {
+                self.len -= 1;
+            }
+
+            if by_table.is_empty() {
+                self.by_type.remove(&type_id);
+            }
+        }
+    }
+}
+
+pub(crate) struct SelectSqlCache {
+    capacity: usize,
+    items: Mutex<CacheItems>,
+}
+
+impl SelectSqlCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: Mutex::new(CacheItems::default()),
+        }
+    }
+
+    fn get_or_create<TEntity: SelectEntity + 'static>(
+        &self,
+        table_name: &str,
+    ) -> Arc<CachedSelect> {
+        let type_id = TypeId::of::<TEntity>();
+
+        let mut items = self.items.lock().unwrap();
+
+        if let Some(result) = items.get(type_id, table_name) {
+            return result;
+        }
+
+        let select_builder = SelectBuilder::from_select_model::<TEntity>();
+
+        let mut select_prefix = String::new();
+        select_builder.fill_select_prefix(&mut select_prefix, table_name);
+
+        let order_by_columns = select_builder.get_order_by_columns();
+        let group_by_columns = select_builder.get_group_by_columns().map(|s| s.to_string());
+
+        let count_prefix = group_by_columns
+            .as_ref()
+            .map(|_| format!("SELECT COUNT(*) FROM ({}", select_prefix));
+
+        let mut select_suffix = String::new();
+        select_suffix.push_str(group_by_columns.as_deref().unwrap_or_default());
+        select_suffix.push_str(order_by_columns.unwrap_or_default());
+
+        let result = Arc::new(CachedSelect {
+            select_prefix,
+            count_prefix,
+            order_by_columns,
+            group_by_columns,
+            select_suffix,
+        });
+
+        if self.capacity > 0 {
+            if items.len >= self.capacity {
+                items.remove_least_recently_used();
+            }
+
+            items.insert(type_id, table_name, result.clone());
+        }
+
+        result
+    }
+
+    pub fn build_select_sql<TEntity: SelectEntity + 'static, TWhereModel: SqlWhereModel>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+    ) -> SqlData {
+        self.build_select_with_having_sql::<TEntity, TWhereModel, NoneHavingModel>(
+            table_name,
+            where_model,
+            None,
+        )
+    }
+
+    pub fn build_select_with_having_sql<
+        TEntity: SelectEntity + 'static,
+        TWhereModel: SqlWhereModel,
+        THavingModel: SqlHavingModel,
+    >(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        having_model: Option<&THavingModel>,
+    ) -> SqlData {
+        let cached = self.get_or_create::<TEntity>(table_name);
+
+        let mut sql = cached.select_prefix.clone();
+        let mut values = SqlValues::new();
+
+        let has_having = having_model.is_some_and(|having_model| having_model.has_conditions());
+
+        if has_having {
+            crate::sql::fill_select_conditions(
+                &mut sql,
+                &mut values,
+                where_model,
+                having_model,
+                cached.order_by_columns,
+                cached.group_by_columns.as_deref(),
+            );
+        } else {
+            fill_where(&mut sql, &mut values, where_model);
+            sql.push_str(cached.select_suffix.as_str());
+        }
+
+        if let Some(where_model) = where_model {
+            where_model.fill_limit_and_offset(&mut sql);
+        }
+
+        SqlData::new(sql, values)
+    }
+
+    pub fn build_count_page_sql<TEntity: SelectEntity + 'static, TWhereModel: SqlWhereModel>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+    ) -> SqlData {
+        let cached = self.get_or_create::<TEntity>(table_name);
+
+        let (Some(count_prefix), Some(group_by_columns)) = (
+            cached.count_prefix.as_deref(),
+            cached.group_by_columns.as_deref(),
+        ) else {
+            return crate::sql::build_count_sql(table_name, where_model);
+        };
+
+        let mut sql = count_prefix.to_string();
+        let mut values = SqlValues::new();
+
+        fill_where(&mut sql, &mut values, where_model);
+        sql.push_str(group_by_columns);
+        sql.push(')');
+
+        SqlData::new(sql, values)
+    }
+
+    pub fn build_select_page_sql<TEntity: SelectEntity + 'static, TWhereModel: SqlWhereModel>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        limit: i64,
+        offset: i64,
+    ) -> SqlData {
+        let cached = self.get_or_create::<TEntity>(table_name);
+
+        let mut sql = cached.select_prefix.clone();
+        let mut values = SqlValues::new();
+
+        fill_where(&mut sql, &mut values, where_model);
+        sql.push_str(cached.select_suffix.as_str());
+
+        let limit_index = values.push_i64(limit);
+        let offset_index = values.push_i64(offset);
+
+        sql.push_str(" LIMIT $");
+        sql.push_str(limit_index.to_string().as_str());
+        sql.push_str(" OFFSET $");
+        sql.push_str(offset_index.to_string().as_str());
+
+        SqlData::new(sql, values)
+    }
+}
+
+fn fill_where<TWhereModel: SqlWhereModel>(
+    sql: &mut String,
+    values: &mut SqlValues,
+    where_model: Option<&TWhereModel>,
+) {
+    crate::sql::fill_select_conditions(
+        sql,
+        values,
+        where_model,
+        NoneHavingModel::new(),
+        None,
+        None,
+    );
+}
@@ -27,3 +27,19 @@ pub fn build_bulk_insert_or_update_sql<TSqlInsertModel: SqlInsertModel + SqlUpda
 
     sql_data
 }
+
+pub fn build_bulk_insert_or_update_sql_chunks<
+    TSqlInsertModel: SqlInsertModel + SqlUpdateModel,
+>(
+    table_name: &str,
+    insert_or_update_models: &[TSqlInsertModel],
+) -> Vec<SqlData> {
+    if insert_or_update_models.len() == 0 {
+        panic!("No models to insert");
+    }
+
+    insert_or_update_models
+        .chunks(super::get_bulk_insert_chunk_size::<TSqlInsertModel>())
+        .map(|chunk| build_bulk_insert_or_update_sql(table_name, chunk))
+        .collect()
+}
@@ -2,6 +2,9 @@ use crate::sql_insert::SqlInsertModel;
 
 use super::{SqlData, SqlValues, UsedColumns};
 
+pub const SQLITE_MAX_VARIABLE_NUMBER: usize = 32766;
+
+#[derive(Clone, Copy)]
 pub enum InsertType {
     JustInsert,
     OrIgnore,
@@ -45,6 +48,28 @@ pub fn build_bulk_insert_sql<TSqlInsertModel: SqlInsertModel>(
     SqlData::new(result, params)
 }
 
+pub fn build_bulk_insert_sql_chunks<TSqlInsertModel: SqlInsertModel>(
+    insert_type: InsertType,
+    models: &[TSqlInsertModel],
+    table_name: &str,
+    used_columns: &UsedColumns,
+) -> Vec<SqlData> {
+    if models.is_empty() {
+        panic!("No models to insert");
+    }
+
+    models
+        .chunks(get_bulk_insert_chunk_size::<TSqlInsertModel>())
+        .map(|chunk| build_bulk_insert_sql(insert_type, chunk, table_name, used_columns))
+        .collect()
+}
+
+// Every field renders at most one bound parameter
+pub fn get_bulk_insert_chunk_size<TSqlInsertModel: SqlInsertModel>() -> usize {
+    let fields_amount = TSqlInsertModel::get_fields_amount().max(1);
+    (SQLITE_MAX_VARIABLE_NUMBER / fields_amount).max(1)
+}
+
 fn fill_bulk_insert_values_sql<TSqlInsertModel: SqlInsertModel>(
     models: &[impl SqlInsertModel],
     sql: &mut String,
@@ -0,0 +1,124 @@
+use crate::sql_where::SqlWhereModel;
+
+use super::{SqlData, SqlValues};
+
+pub fn build_count_sql<TWhereModel: SqlWhereModel>(
+    table_name: &str,
+    where_model: Option<&TWhereModel>,
+) -> SqlData {
+    let mut sql = String::new();
+    let mut params = SqlValues::new();
+
+    sql.push_str("SELECT COUNT(*)");
+    fill_from_and_where(&mut sql, &mut params, table_name, where_model);
+
+    SqlData::new(sql, params)
+}
+
+pub fn build_count_distinct_sql<TWhereModel: SqlWhereModel>(
+    table_name: &str,
+    column_name: &str,
+    where_model: Option<&TWhereModel>,
+) -> SqlData {
+    let mut sql = String::new();
+    let mut params = SqlValues::new();
+
+    sql.push_str("SELECT COUNT(DISTINCT ");
+    crate::push_quoted_identifier(&mut sql, column_name);
+    sql.push(')');
+    fill_from_and_where(&mut sql, &mut params, table_name, where_model);
+
+    SqlData::new(sql, params)
+}
+
+pub fn build_count_grouped_by_sql<TWhereModel: SqlWhereModel>(
+    table_name: &str,
+    column_name: &str,
+    where_model: Option<&TWhereModel>,
+) -> SqlData {
+    let mut sql = String::new();
+    let mut params = SqlValues::new();
+
+    let column_name = crate::quote_identifier(column_name);
+
+    sql.push_str("SELECT ");
+    sql.push_str(column_name.as_str());
+    sql.push_str(",COUNT(*)");
+    fill_from_and_where(&mut sql, &mut params, table_name, where_model);
+
+    sql.push_str(" GROUP BY ");
+    sql.push_str(column_name.as_str());
+    sql.push_str(" ORDER BY ");
+    sql.push_str(column_name.as_str());
+
+    SqlData::new(sql, params)
+}
+
+pub fn build_aggregate_sql<TWhereModel: SqlWhereModel>(
+    table_name: &str,
+    statement: &str,
+    where_model: Option<&TWhereModel>,
+) -> SqlData {
+    let mut sql = String::new();
+    let mut params = SqlValues::new();
+
+    sql.push_str("SELECT ");
+    sql.push_str(statement);
+    fill_from_and_where(&mut sql, &mut params, table_name, where_model);
+
+    SqlData::new(sql, params)
+}
+
+pub fn build_exists_sql<TWhereModel: SqlWhereModel>(
+    table_name: &str,
+    where_model: Option<&TWhereModel>,
+) -> SqlData {
+    let mut sql = String::new();
+    let mut params = SqlValues::new();
+
+    sql.push_str("SELECT EXISTS(SELECT 1");
+    fill_from_and_where(&mut sql, &mut params, table_name, where_model);
+    sql.push(')');
+
+    SqlData::new(sql, params)
+}
+
+fn fill_from_and_where<TWhereModel: SqlWhereModel>(
+    sql: &mut String,
+    params: &mut SqlValues,
+    table_name: &str,
+    where_model: Option<&TWhereModel>,
+) {
+    sql.push_str(" FROM ");
+    sql.push_str(table_name);
+
+    if let Some(where_model) = where_model {
+        if where_model.has_conditions() {
+            sql.push_str(" WHERE ");
+            where_model.fill_where_component(sql, params);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql_where::NoneWhereModel;
+
+    #[test]
+    fn test_count_sql() {
+        let sql_data = super::build_count_sql::<NoneWhereModel>("items", None);
+        assert_eq!(sql_data.sql, "SELECT COUNT(*) FROM items");
+
+        let sql_data = super::build_count_distinct_sql::<NoneWhereModel>("items", "kind", None);
+        assert_eq!(sql_data.sql, "SELECT COUNT(DISTINCT \"kind\") FROM items");
+
+        let sql_data = super::build_count_grouped_by_sql::<NoneWhereModel>("items", "kind", None);
+        assert_eq!(
+            sql_data.sql,
+            "SELECT \"kind\",COUNT(*) FROM items GROUP BY \"kind\" ORDER BY \"kind\""
+        );
+
+        let sql_data = super::build_exists_sql::<NoneWhereModel>("items", None);
+        assert_eq!(sql_data.sql, "SELECT EXISTS(SELECT 1 FROM items)");
+    }
+}
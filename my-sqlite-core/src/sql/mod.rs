@@ -1,5 +1,6 @@
 mod build_bulk_insert_or_update_sql;
 mod build_bulk_insert_sql;
+mod build_count_sql;
 mod build_insert_or_update_sql;
 mod build_insert_sql;
 mod build_update_sql;
@@ -13,6 +14,7 @@ mod sql_string;
 mod where_builder;
 pub use build_bulk_insert_or_update_sql::*;
 pub use build_bulk_insert_sql::*;
+pub use build_count_sql::*;
 pub use build_insert_or_update_sql::*;
 pub use build_insert_sql::*;
 pub use build_update_sql::*;
@@ -1,6 +1,7 @@
 use rust_extensions::StrOrString;
 
 use crate::{
+    sql_having::{NoneHavingModel, SqlHavingModel},
     sql_select::{DbColumnName, SelectEntity},
     sql_where::SqlWhereModel,
 };
@@ -17,6 +18,14 @@ pub enum SelectFieldValue {
         statement: StrOrString<'static>,
         field_name: DbColumnName,
     },
+    GroupByKey {
+        statement: StrOrString<'static>,
+        field_name: DbColumnName,
+    },
+    Expression {
+        statement: StrOrString<'static>,
+        field_name: DbColumnName,
+    },
 }
 
 impl SelectFieldValue {
@@ -34,6 +43,12 @@ impl SelectFieldValue {
             SelectFieldValue::GroupByField { field_name, .. } => {
                 panic!("Value is GroupByField: {:?}", field_name)
             }
+            SelectFieldValue::GroupByKey { field_name, .. } => {
+                panic!("Value is GroupByKey: {:?}", field_name)
+            }
+            SelectFieldValue::Expression { field_name, .. } => {
+                panic!("Value is Expression: {:?}", field_name)
+            }
         }
     }
 
@@ -51,6 +66,12 @@ impl SelectFieldValue {
             SelectFieldValue::GroupByField { field_name, .. } => {
                 panic!("Value is GroupByField: {:?}", field_name)
             }
+            SelectFieldValue::GroupByKey { field_name, .. } => {
+                panic!("Value is GroupByKey: {:?}", field_name)
+            }
+            SelectFieldValue::Expression { field_name, .. } => {
+                panic!("Value is Expression: {:?}", field_name)
+            }
         }
     }
 
@@ -68,6 +89,12 @@ impl SelectFieldValue {
             SelectFieldValue::GroupByField { field_name, .. } => {
                 panic!("Value is GroupByField: {:?}", field_name)
             }
+            SelectFieldValue::GroupByKey { field_name, .. } => {
+                panic!("Value is GroupByKey: {:?}", field_name)
+            }
+            SelectFieldValue::Expression { field_name, .. } => {
+                panic!("Value is Expression: {:?}", field_name)
+            }
         }
     }
 
@@ -83,6 +110,12 @@ impl SelectFieldValue {
             SelectFieldValue::GroupByField { field_name, .. } => {
                 panic!("Value is GroupByField: {:?}", field_name)
             }
+            SelectFieldValue::GroupByKey { field_name, .. } => {
+                panic!("Value is GroupByKey: {:?}", field_name)
+            }
+            SelectFieldValue::Expression { field_name, .. } => {
+                panic!("Value is Expression: {:?}", field_name)
+            }
         }
     }
 
@@ -97,6 +130,12 @@ impl SelectFieldValue {
             SelectFieldValue::GroupByField { field_name, .. } => {
                 panic!("Value is GroupByField: {:?}", field_name)
             }
+            SelectFieldValue::GroupByKey { field_name, .. } => {
+                panic!("Value is GroupByKey: {:?}", field_name)
+            }
+            SelectFieldValue::Expression { field_name, .. } => {
+                panic!("Value is Expression: {:?}", field_name)
+            }
             SelectFieldValue::DateTimeAsTimestamp(field_name) => field_name,
         }
     }
@@ -105,7 +144,7 @@ impl SelectFieldValue {
 pub struct SelectBuilder {
     items: Vec<SelectFieldValue>,
     order_by_columns: Option<&'static str>,
-    group_by_columns: Option<&'static str>,
+    group_by_columns: Option<String>,
 }
 
 impl SelectBuilder {
@@ -121,9 +160,24 @@ impl SelectBuilder {
         let mut builder = Self::new();
         TSelectEntity::fill_select_fields(&mut builder);
 
-        builder.group_by_columns = TSelectEntity::get_group_by_fields();
         builder.order_by_columns = TSelectEntity::get_order_by_fields();
 
+        let mut group_by_columns = TSelectEntity::get_group_by_fields().map(|s| s.to_string());
+
+        for item in builder.items.iter() {
+            if let SelectFieldValue::GroupByKey { statement, .. } = item {
+                match group_by_columns.as_mut() {
+                    Some(group_by_columns) => {
+                        group_by_columns.push(',');
+                        group_by_columns.push_str(statement.as_str());
+                    }
+                    None => group_by_columns = Some(format!(" GROUP BY {}", statement.as_str())),
+                }
+            }
+        }
+
+        builder.group_by_columns = group_by_columns;
+
         builder
     }
 
@@ -143,6 +197,18 @@ impl SelectBuilder {
         fill_select_fields(sql, &self.items)
     }
 
+    pub fn fill_select_prefix(&self, sql: &mut String, table_name: &str) {
+        fill_select_prefix(sql, table_name, &self.items)
+    }
+
+    pub fn get_order_by_columns(&self) -> Option<&'static str> {
+        self.order_by_columns
+    }
+
+    pub fn get_group_by_columns(&self) -> Option<&str> {
+        self.group_by_columns.as_deref()
+    }
+
     pub fn to_sql_string<TSqlWhereModel: SqlWhereModel>(
         &self,
         table_name: &str,
@@ -157,7 +223,7 @@ impl SelectBuilder {
             self.items.as_slice(),
             where_model,
             self.order_by_columns,
-            self.group_by_columns,
+            self.group_by_columns.as_deref(),
         );
 
         SqlData { sql, values }
@@ -177,7 +243,7 @@ impl SelectBuilder {
             self.items.as_slice(),
             where_model,
             self.order_by_columns,
-            self.group_by_columns,
+            self.group_by_columns.as_deref(),
         );
     }
 }
@@ -189,15 +255,100 @@ pub fn build_select<TSqlWhereModel: SqlWhereModel>(
     items: &[SelectFieldValue],
     where_model: Option<&TSqlWhereModel>,
     order_by_columns: Option<&'static str>,
-    group_by_columns: Option<&'static str>,
+    group_by_columns: Option<&str>,
 ) {
+    build_select_with_having(
+        sql,
+        values,
+        table_name,
+        items,
+        where_model,
+        NoneHavingModel::new(),
+        order_by_columns,
+        group_by_columns,
+    );
+}
+
+pub fn build_select_with_having<TSqlWhereModel: SqlWhereModel, TSqlHavingModel: SqlHavingModel>(
+    sql: &mut String,
+    values: &mut SqlValues,
+    table_name: &str,
+    items: &[SelectFieldValue],
+    where_model: Option<&TSqlWhereModel>,
+    having_model: Option<&TSqlHavingModel>,
+    order_by_columns: Option<&'static str>,
+    group_by_columns: Option<&str>,
+) {
+    fill_select_prefix(sql, table_name, items);
+    fill_select_suffix_with_having(
+        sql,
+        values,
+        where_model,
+        having_model,
+        order_by_columns,
+        group_by_columns,
+    );
+}
+
+pub fn fill_select_prefix(sql: &mut String, table_name: &str, items: &[SelectFieldValue]) {
     sql.push_str("SELECT ");
 
     fill_select_fields(sql, items);
 
     sql.push_str(" FROM ");
     sql.push_str(table_name);
+}
+
+pub fn fill_select_suffix<TSqlWhereModel: SqlWhereModel>(
+    sql: &mut String,
+    values: &mut SqlValues,
+    where_model: Option<&TSqlWhereModel>,
+    order_by_columns: Option<&'static str>,
+    group_by_columns: Option<&str>,
+) {
+    fill_select_suffix_with_having(
+        sql,
+        values,
+        where_model,
+        NoneHavingModel::new(),
+        order_by_columns,
+        group_by_columns,
+    );
+}
+
+pub fn fill_select_suffix_with_having<
+    TSqlWhereModel: SqlWhereModel,
+    TSqlHavingModel: SqlHavingModel,
+>(
+    sql: &mut String,
+    values: &mut SqlValues,
+    where_model: Option<&TSqlWhereModel>,
+    having_model: Option<&TSqlHavingModel>,
+    order_by_columns: Option<&'static str>,
+    group_by_columns: Option<&str>,
+) {
+    fill_select_conditions(
+        sql,
+        values,
+        where_model,
+        having_model,
+        order_by_columns,
+        group_by_columns,
+    );
 
+    if let Some(where_model) = where_model {
+        where_model.fill_limit_and_offset(sql);
+    }
+}
+
+pub fn fill_select_conditions<TSqlWhereModel: SqlWhereModel, TSqlHavingModel: SqlHavingModel>(
+    sql: &mut String,
+    values: &mut SqlValues,
+    where_model: Option<&TSqlWhereModel>,
+    having_model: Option<&TSqlHavingModel>,
+    order_by_columns: Option<&'static str>,
+    group_by_columns: Option<&str>,
+) {
     if let Some(where_model) = where_model {
         if where_model.has_conditions() {
             sql.push_str(" WHERE ");
@@ -209,12 +360,15 @@ pub fn build_select<TSqlWhereModel: SqlWhereModel>(
         sql.push_str(group_by_fields);
     }
 
-    if let Some(order_by_fields) = order_by_columns {
-        sql.push_str(order_by_fields);
+    if let Some(having_model) = having_model {
+        if having_model.has_conditions() {
+            sql.push_str(" HAVING ");
+            having_model.fill_having_component(sql, values);
+        }
     }
 
-    if let Some(where_model) = where_model {
-        where_model.fill_limit_and_offset(sql);
+    if let Some(order_by_fields) = order_by_columns {
+        sql.push_str(order_by_fields);
     }
 }
 
@@ -252,6 +406,14 @@ pub fn fill_select_fields(sql: &mut String, items: &[SelectFieldValue]) {
             SelectFieldValue::GroupByField {
                 field_name,
                 statement,
+            }
+            | SelectFieldValue::GroupByKey {
+                field_name,
+                statement,
+            }
+            | SelectFieldValue::Expression {
+                field_name,
+                statement,
             } => {
                 sql.push_str(
                     format!("{} as \"{}\"", statement.as_str(), field_name.field_name).as_str(),
@@ -1,4 +1,5 @@
-use async_sqlite::rusqlite::ToSql;
+use async_sqlite::rusqlite::{types::ToSqlOutput, ToSql};
+use serde::Serialize;
 
 #[derive(Debug)]
 pub enum NonStringValue {
@@ -7,6 +8,7 @@ pub enum NonStringValue {
     BigInt(i64),
     Float(f32),
     Double(f64),
+    Blob(Vec<u8>),
 }
 
 impl NonStringValue {
@@ -17,15 +19,30 @@ impl NonStringValue {
             NonStringValue::BigInt(value) => value,
             NonStringValue::Float(value) => value,
             NonStringValue::Double(value) => value,
+            NonStringValue::Blob(value) => value,
         }
     }
 }
 
+// A value which could not be converted into a parameter. Binding it fails the query,
+// so the error reaches the caller as a Result instead of a panic.
+#[derive(Debug)]
+pub struct InvalidSqlValue(pub String);
+
+impl ToSql for InvalidSqlValue {
+    fn to_sql(&self) -> async_sqlite::rusqlite::Result<ToSqlOutput<'_>> {
+        Err(async_sqlite::rusqlite::Error::ToSqlConversionFailure(
+            self.0.clone().into(),
+        ))
+    }
+}
+
 #[derive(Debug)]
 pub enum SqlString {
     AsString(String),
     AsStr(&'static str),
     NonStrValue(NonStringValue),
+    Invalid(InvalidSqlValue),
 }
 
 impl SqlString {
@@ -37,6 +54,13 @@ impl SqlString {
         Self::AsStr(src)
     }
 
+    pub fn from_json<T: Serialize + ?Sized>(value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(value) => Self::AsString(value),
+            Err(err) => Self::Invalid(InvalidSqlValue(format!("Can not serialize json: {}", err))),
+        }
+    }
+
     pub fn as_str(&self) -> Option<&str> {
         match self {
             SqlString::AsString(value) => Some(value.as_str()),
@@ -45,11 +69,28 @@ impl SqlString {
         }
     }
 
+    pub fn to_log_string(&self) -> String {
+        match self {
+            SqlString::AsString(value) => format!("'{}'", value),
+            SqlString::AsStr(value) => format!("'{}'", value),
+            SqlString::NonStrValue(value) => match value {
+                NonStringValue::SmallInt(value) => value.to_string(),
+                NonStringValue::Integer(value) => value.to_string(),
+                NonStringValue::BigInt(value) => value.to_string(),
+                NonStringValue::Float(value) => value.to_string(),
+                NonStringValue::Double(value) => value.to_string(),
+                NonStringValue::Blob(value) => format!("<blob {} bytes>", value.len()),
+            },
+            SqlString::Invalid(value) => format!("<invalid: {}>", value.0),
+        }
+    }
+
     pub fn to_sql(&self) -> &dyn ToSql {
         match self {
             SqlString::AsString(value) => value,
             SqlString::AsStr(value) => value,
             SqlString::NonStrValue(value) => value.to_sql(),
+            SqlString::Invalid(value) => value,
         }
     }
 }
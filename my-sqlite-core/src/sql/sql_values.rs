@@ -1,6 +1,7 @@
 use async_sqlite::rusqlite::ToSql;
+use serde::Serialize;
 
-use super::sql_string::SqlString;
+use super::sql_string::{NonStringValue, SqlString};
 
 const EMPTY: SqlValues = SqlValues::Empty;
 pub enum SqlValues {
@@ -63,6 +64,22 @@ impl SqlValues {
         self.push(SqlString::from_static_str(value))
     }
 
+    pub fn push_i64(&mut self, value: i64) -> usize {
+        self.push(SqlString::NonStrValue(NonStringValue::BigInt(value)))
+    }
+
+    pub fn push_f64(&mut self, value: f64) -> usize {
+        self.push(SqlString::NonStrValue(NonStringValue::Double(value)))
+    }
+
+    pub fn push_json<T: Serialize + ?Sized>(&mut self, value: &T) -> usize {
+        self.push(SqlString::from_json(value))
+    }
+
+    pub fn push_blob(&mut self, value: Vec<u8>) -> usize {
+        self.push(SqlString::NonStrValue(NonStringValue::Blob(value)))
+    }
+
     pub fn get_params_to_invoke(&self) -> Vec<&dyn ToSql> {
         match self {
             SqlValues::Values(values) => {
@@ -78,6 +95,13 @@ impl SqlValues {
         }
     }
 
+    pub fn get_log_params(&self) -> Vec<String> {
+        match self {
+            SqlValues::Values(values) => values.iter().map(|itm| itm.to_log_string()).collect(),
+            SqlValues::Empty => vec![],
+        }
+    }
+
     pub fn empty() -> &'static SqlValues {
         &EMPTY
     }
@@ -1,8 +1,20 @@
+use crate::ColumnName;
+
 pub enum SqlUpdateValue {
     Index(usize),
     StringValue(String),
     NonStringValue(String),
     Json(usize),
+    JsonSet {
+        path_index: usize,
+        empty_document: &'static str,
+        value: Box<SqlUpdateValue>,
+    },
+    JsonRemove {
+        path_index: usize,
+        empty_document: &'static str,
+    },
+    JsonPatch(usize),
 }
 
 impl SqlUpdateValue {
@@ -16,6 +28,9 @@ impl SqlUpdateValue {
             SqlUpdateValue::Json(value) => {
                 panic!("Type is Json: {}", value)
             }
+            SqlUpdateValue::JsonSet { .. } => panic!("Type is JsonSet"),
+            SqlUpdateValue::JsonRemove { .. } => panic!("Type is JsonRemove"),
+            SqlUpdateValue::JsonPatch(index) => panic!("Type is JsonPatch: {}", index),
         }
     }
 
@@ -29,6 +44,9 @@ impl SqlUpdateValue {
             SqlUpdateValue::Json(value) => {
                 panic!("Type is Json: {}", value)
             }
+            SqlUpdateValue::JsonSet { .. } => panic!("Type is JsonSet"),
+            SqlUpdateValue::JsonRemove { .. } => panic!("Type is JsonRemove"),
+            SqlUpdateValue::JsonPatch(index) => panic!("Type is JsonPatch: {}", index),
         }
     }
 
@@ -42,6 +60,9 @@ impl SqlUpdateValue {
             SqlUpdateValue::Json(value) => {
                 panic!("Type is Json: {}", value)
             }
+            SqlUpdateValue::JsonSet { .. } => panic!("Type is JsonSet"),
+            SqlUpdateValue::JsonRemove { .. } => panic!("Type is JsonRemove"),
+            SqlUpdateValue::JsonPatch(index) => panic!("Type is JsonPatch: {}", index),
         }
     }
 
@@ -55,10 +76,21 @@ impl SqlUpdateValue {
                 panic!("Type is NonStringValue: {}", value.as_str())
             }
             SqlUpdateValue::Json(value) => *value,
+            SqlUpdateValue::JsonSet { .. } => panic!("Type is JsonSet"),
+            SqlUpdateValue::JsonRemove { .. } => panic!("Type is JsonRemove"),
+            SqlUpdateValue::JsonPatch(index) => panic!("Type is JsonPatch: {}", index),
         }
     }
 
     pub fn write(&self, sql: &mut String) {
+        self.write_with_target(sql, None);
+    }
+
+    pub fn write_for_column(&self, column_name: &ColumnName, sql: &mut String) {
+        self.write_with_target(sql, Some(column_name));
+    }
+
+    fn write_with_target(&self, sql: &mut String, column_name: Option<&ColumnName>) {
         match self {
             SqlUpdateValue::Index(index) => {
                 sql.push('$');
@@ -76,6 +108,60 @@ impl SqlUpdateValue {
                 sql.push_str("$");
                 sql.push_str(index.to_string().as_str());
             }
+            SqlUpdateValue::JsonSet {
+                path_index,
+                empty_document,
+                value,
+            } => {
+                sql.push_str("json_set(");
+                write_json_target(sql, column_name, empty_document);
+                sql.push_str(",$");
+                sql.push_str(path_index.to_string().as_str());
+                sql.push(',');
+
+                if let SqlUpdateValue::Json(index) = value.as_ref() {
+                    sql.push_str("json($");
+                    sql.push_str(index.to_string().as_str());
+                    sql.push(')');
+                } else {
+                    value.write(sql);
+                }
+
+                sql.push(')');
+            }
+            SqlUpdateValue::JsonRemove {
+                path_index,
+                empty_document,
+            } => {
+                sql.push_str("json_remove(");
+                write_json_target(sql, column_name, empty_document);
+                sql.push_str(",$");
+                sql.push_str(path_index.to_string().as_str());
+                sql.push(')');
+            }
+            SqlUpdateValue::JsonPatch(index) => {
+                sql.push_str("json_patch(");
+                write_json_target(sql, column_name, "{}");
+                sql.push_str(",$");
+                sql.push_str(index.to_string().as_str());
+                sql.push(')');
+            }
         }
     }
 }
+
+fn write_json_target(sql: &mut String, column_name: Option<&ColumnName>, empty_document: &str) {
+    if let Some(column_name) = column_name {
+        sql.push_str("coalesce(");
+        column_name.push_name(sql);
+        sql.push(',');
+    }
+
+    sql.push('\'');
+    sql.push_str(empty_document);
+    sql.push('\'');
+
+    if column_name.is_some() {
+        sql.push(')');
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use async_sqlite::rusqlite::types::Value;
+use futures_core::Stream;
+
+use crate::{
+    sql::{SelectBuilder, SqlData, SqlValues},
+    sql_select::SelectEntity,
+    sql_where::SqlWhereModel,
+    table_schema::TableSchemaProvider,
+    ColumnName, SqlLiteConnection, SqlLiteError, SqlRequest,
+};
+
+type ChunkFuture<'s, TEntity> = Pin<
+    Box<dyn Future<Output = Result<(Vec<TEntity>, Option<Vec<Value>>), SqlLiteError>> + Send + 's>,
+>;
+
+pub struct SqlChunksReader<'s, TEntity, TWhereModel>
+where
+    TEntity: SelectEntity + TableSchemaProvider + Send + Sync + 'static,
+    TWhereModel: SqlWhereModel,
+{
+    connection: &'s SqlLiteConnection,
+    request: SqlRequest<'s>,
+    where_model: Option<&'s TWhereModel>,
+    chunk_size: usize,
+    select_prefix: String,
+    primary_key_columns: Vec<ColumnName>,
+    last_key: Option<Vec<Value>>,
+    finished: bool,
+    pending_error: Option<SqlLiteError>,
+    pending_chunk: Option<ChunkFuture<'s, TEntity>>,
+}
+
+impl<'s, TEntity, TWhereModel> SqlChunksReader<'s, TEntity, TWhereModel>
+where
+    TEntity: SelectEntity + TableSchemaProvider + Send + Sync + 'static,
+    TWhereModel: SqlWhereModel,
+{
+    pub(crate) fn new(
+        connection: &'s SqlLiteConnection,
+        request: SqlRequest<'s>,
+        where_model: Option<&'s TWhereModel>,
+        chunk_size: usize,
+    ) -> Result<Self, SqlLiteError> {
+        if chunk_size == 0 {
+            return Err(SqlLiteError::InvalidArgument {
+                message: "query_rows_as_chunks requires chunk_size greater than 0".to_string(),
+            });
+        }
+
+        let mut result = Self {
+            connection,
+            request,
+            where_model,
+            chunk_size,
+            select_prefix: String::new(),
+            primary_key_columns: Vec::new(),
+            last_key: None,
+            finished: false,
+            pending_error: None,
+            pending_chunk: None,
+        };
+
+        let primary_key_columns = match TEntity::get_primary_key_columns() {
+            Some(columns) if columns.len() > 0 => columns,
+            _ => {
+                result.pending_error = Some(SqlLiteError::InvalidArgument {
+                    message: format!(
+                        "Table {} has no primary key to paginate query_rows_as_chunks",
+                        request.table_name
+                    ),
+                });
+                return Ok(result);
+            }
+        };
+
+        let select_builder = SelectBuilder::from_select_model::<TEntity>();
+
+        if select_builder.get_group_by_columns().is_some() {
+            result.pending_error = Some(SqlLiteError::InvalidArgument {
+                message: "query_rows_as_chunks does not support entities with group_by fields"
+                    .to_string(),
+            });
+            return Ok(result);
+        }
+
+        result.select_prefix.push_str("SELECT ");
+        select_builder.fill_select_fields(&mut result.select_prefix);
+
+        for column in primary_key_columns.iter() {
+            result.select_prefix.push(',');
+            column.push_name(&mut result.select_prefix);
+        }
+
+        result.select_prefix.push_str(" FROM ");
+        result.select_prefix.push_str(request.table_name);
+
+        result.primary_key_columns = primary_key_columns;
+
+        Ok(result)
+    }
+
+    pub async fn get_next(&mut self) -> Option<Result<Vec<TEntity>, SqlLiteError>> {
+        std::future::poll_fn(|cx| self.poll_next_chunk(cx)).await
+    }
+
+    fn poll_next_chunk(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Vec<TEntity>, SqlLiteError>>> {
+        if self.finished {
+            return Poll::Ready(None);
+        }
+
+        if let Some(err) = self.pending_error.take() {
+            self.finished = true;
+            return Poll::Ready(Some(Err(err)));
+        }
+
+        if self.pending_chunk.is_none() {
+            let sql_data = self.build_sql();
+
+            let connection = self.connection;
+            self.pending_chunk = Some(Box::pin(connection.query_chunk::<TEntity>(
+                self.request,
+                sql_data,
+                self.primary_key_columns.len(),
+            )));
+        }
+
+        let result = match self.pending_chunk.as_mut().unwrap().as_mut().poll(cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => return Poll::Pending,
+        };
+
+        self.pending_chunk = None;
+
+        match result {
+            Ok((items, last_key)) => {
+                if items.len() < self.chunk_size {
+                    self.finished = true;
+                }
+
+                if items.len() == 0 {
+                    return Poll::Ready(None);
+                }
+
+                self.last_key = last_key;
+                Poll::Ready(Some(Ok(items)))
+            }
+            Err(err) => {
+                self.finished = true;
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+    }
+
+    fn build_sql(&self) -> SqlData {
+        let mut sql = self.select_prefix.clone();
+        let mut values = SqlValues::new();
+
+        let mut has_where = false;
+
+        if let Some(where_model) = self.where_model {
+            if where_model.has_conditions() {
+                sql.push_str(" WHERE (");
+                where_model.fill_where_component(&mut sql, &mut values);
+                sql.push(')');
+                has_where = true;
+            }
+        }
+
+        if let Some(last_key) = self.last_key.as_ref() {
+            if has_where {
+                sql.push_str(" AND ");
+            } else {
+                sql.push_str(" WHERE ");
+            }
+
+            sql.push('(');
+            self.fill_primary_key_columns(&mut sql);
+            sql.push_str(")>(");
+
+            for (no, value) in last_key.iter().enumerate() {
+                if no > 0 {
+                    sql.push(',');
+                }
+
+                let index = crate::push_key_value(&mut values, value);
+
+                sql.push('$');
+                sql.push_str(index.to_string().as_str());
+            }
+
+            sql.push(')');
+        }
+
+        sql.push_str(" ORDER BY ");
+        self.fill_primary_key_columns(&mut sql);
+
+        sql.push_str(" LIMIT ");
+        sql.push_str(self.chunk_size.to_string().as_str());
+
+        SqlData::new(sql, values)
+    }
+
+    fn fill_primary_key_columns(&self, sql: &mut String) {
+        for (no, column) in self.primary_key_columns.iter().enumerate() {
+            if no > 0 {
+                sql.push(',');
+            }
+
+            column.push_name(sql);
+        }
+    }
+}
+
+impl<'s, TEntity, TWhereModel> Stream for SqlChunksReader<'s, TEntity, TWhereModel>
+where
+    TEntity: SelectEntity + TableSchemaProvider + Send + Sync + 'static,
+    TWhereModel: SqlWhereModel,
+{
+    type Item = Result<Vec<TEntity>, SqlLiteError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next_chunk(cx)
+    }
+}
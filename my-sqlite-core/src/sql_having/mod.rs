@@ -0,0 +1,4 @@
+mod sql_having_model;
+pub use sql_having_model::*;
+mod none_having_model;
+pub use none_having_model::*;
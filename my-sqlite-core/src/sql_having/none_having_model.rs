@@ -0,0 +1,19 @@
+use crate::sql::SqlValues;
+
+use super::SqlHavingModel;
+
+pub struct NoneHavingModel;
+
+impl NoneHavingModel {
+    pub fn new() -> Option<&'static Self> {
+        None
+    }
+}
+
+impl SqlHavingModel for NoneHavingModel {
+    fn fill_having_component(&self, _sql: &mut String, _params: &mut SqlValues) {}
+
+    fn has_conditions(&self) -> bool {
+        false
+    }
+}
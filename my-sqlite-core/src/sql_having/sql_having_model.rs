@@ -0,0 +1,7 @@
+use crate::sql::SqlValues;
+
+pub trait SqlHavingModel {
+    fn fill_having_component(&self, sql: &mut String, params: &mut SqlValues);
+
+    fn has_conditions(&self) -> bool;
+}
@@ -1,35 +1,325 @@
-use std::sync::Arc;
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use async_sqlite::{rusqlite::types::FromSql, Client};
+use async_sqlite::{
+    rusqlite::{
+        types::{FromSql, Value},
+        Connection, InterruptHandle, OptionalExtension,
+    },
+    Client,
+};
 #[cfg(feature = "with-logs-and-telemetry")]
 use my_telemetry::MyTelemetryContext;
+#[cfg(feature = "with-logs-and-telemetry")]
+use rust_extensions::date_time::DateTimeAsMicroseconds;
 use rust_extensions::StrOrString;
 
 use crate::{
-    sql::{SelectBuilder, SqlValues, UsedColumns},
+    sql::{SelectBuilder, SqlData, SqlValues, UsedColumns},
+    sql_having::SqlHavingModel,
     sql_insert::SqlInsertModel,
-    sql_select::SelectEntity,
+    sql_select::{CursorPageEntity, SelectEntity},
     sql_update::SqlUpdateModel,
     sql_where::SqlWhereModel,
     table_schema::TableSchemaProvider,
-    CountResult, DbRow, SqlLiteError, SqliteQueryStream,
+    AggregateFieldType, ConsoleLogger, CountResult, DbRow, DecodedRows, InterruptGuard, Page,
+    PageCursor, PagedResult, QueryPlan, QueryStreamReporter, SelectSqlCache, SqlChunksReader,
+    SqlLiteError, SqlLiteLogger, SqlLogEvent, SqlLogLevel, SqlMetrics, SqlMetricsRecord,
+    SqlMetricsSnapshot, SqlOperation, SqlRequest, SqlWaitTime, SqliteQueryStream,
 };
+#[cfg(feature = "with-logs-and-telemetry")]
+use crate::{MyTelemetrySink, SqlTelemetrySink};
+
+pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 128;
+pub const DEFAULT_STREAM_CHANNEL_CAPACITY: usize = 2048;
 
 pub struct SqlLiteConnection {
     pub client: Arc<Client>,
-    debug: bool,
+    pub(crate) redact_sql_in_errors: bool,
+    pub(crate) logger: Arc<dyn SqlLiteLogger>,
+    pub(crate) log_level: SqlLogLevel,
+    pub(crate) slow_query_threshold: Option<Duration>,
+    pub(crate) metrics: Arc<SqlMetrics>,
+    pub(crate) select_sql_cache: SelectSqlCache,
+    pub(crate) query_timeout: Option<Duration>,
+    pub(crate) stream_channel_capacity: usize,
+    interrupt_handle: Arc<InterruptHandle>,
+    #[cfg(feature = "with-logs-and-telemetry")]
+    pub(crate) telemetry_sink: Arc<dyn SqlTelemetrySink>,
 }
 
 impl SqlLiteConnection {
     pub async fn new(client: Client, debug: bool) -> Self {
+        let interrupt_handle = client
+            .conn(|conn| Ok(conn.get_interrupt_handle()))
+            .await
+            .expect("Can not get sqlite interrupt handle");
+
         Self {
             client: Arc::new(client),
-            debug,
+            redact_sql_in_errors: false,
+            logger: Arc::new(ConsoleLogger),
+            log_level: if debug {
+                SqlLogLevel::Debug
+            } else {
+                SqlLogLevel::Warn
+            },
+            slow_query_threshold: None,
+            metrics: Arc::new(SqlMetrics::new()),
+            select_sql_cache: SelectSqlCache::new(DEFAULT_STATEMENT_CACHE_CAPACITY),
+            query_timeout: None,
+            stream_channel_capacity: DEFAULT_STREAM_CHANNEL_CAPACITY,
+            interrupt_handle: Arc::new(interrupt_handle),
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_sink: Arc::new(MyTelemetrySink),
         }
     }
 
-    fn is_debug(&self) -> bool {
-        self.debug
+    pub fn get_metrics(&self) -> SqlMetricsSnapshot {
+        self.metrics.get_snapshot()
+    }
+
+    pub fn get_metrics_registry(&self) -> Arc<SqlMetrics> {
+        self.metrics.clone()
+    }
+
+    pub fn interrupt(&self) {
+        self.interrupt_handle.interrupt();
+    }
+
+    pub async fn with_timeout<TResult>(
+        &self,
+        timeout: Duration,
+        future: impl Future<Output = Result<TResult, SqlLiteError>>,
+    ) -> Result<TResult, SqlLiteError> {
+        match tokio::time::timeout(timeout, future).await {
+            Ok(result) => result,
+            Err(_) => Err(SqlLiteError::Timeout { sql: None }),
+        }
+    }
+
+    fn attach_sql(&self, err: impl Into<SqlLiteError>, sql: &str) -> SqlLiteError {
+        err.into().with_sql(sql, self.redact_sql_in_errors)
+    }
+
+    fn write_log<TResult>(
+        &self,
+        request: &SqlRequest,
+        sql_data: &SqlData,
+        duration: Duration,
+        result: &Result<TResult, SqlLiteError>,
+        rows_count: fn(&TResult) -> usize,
+    ) {
+        let level = if result.is_err() {
+            SqlLogLevel::Error
+        } else {
+            SqlLogLevel::Debug
+        };
+
+        if !self.log_level.is_enabled(level) {
+            return;
+        }
+
+        let sql = self.get_sql_to_log(sql_data);
+
+        self.logger.log(&SqlLogEvent {
+            level,
+            operation: request.operation,
+            table_name: request.table_name,
+            sql: sql.as_str(),
+            params_count: sql_data.values.len(),
+            duration,
+            rows: result.as_ref().ok().map(rows_count),
+            error: result.as_ref().err(),
+            params: None,
+            query_plan: None,
+        });
+    }
+
+    fn get_sql_to_log(&self, sql_data: &SqlData) -> String {
+        if self.redact_sql_in_errors {
+            crate::redact_sql(sql_data.sql.as_str())
+        } else {
+            sql_data.sql.clone()
+        }
+    }
+
+    async fn write_slow_query_log(
+        &self,
+        request: &SqlRequest<'_>,
+        sql_data: Arc<SqlData>,
+        duration: Duration,
+    ) {
+        if !self.log_level.is_enabled(SqlLogLevel::Warn) {
+            return;
+        }
+
+        let sql_data_spawned = sql_data.clone();
+
+        let query_plan = self
+            .client
+            .conn(move |conn| QueryPlan::read(conn, &sql_data_spawned))
+            .await
+            .unwrap_or_default();
+
+        let params = if self.redact_sql_in_errors {
+            vec!["?".to_string(); sql_data.values.len()]
+        } else {
+            sql_data.values.get_log_params()
+        };
+
+        let sql = self.get_sql_to_log(&sql_data);
+
+        self.logger.log(&SqlLogEvent {
+            level: SqlLogLevel::Warn,
+            operation: request.operation,
+            table_name: request.table_name,
+            sql: sql.as_str(),
+            params_count: sql_data.values.len(),
+            duration,
+            rows: None,
+            error: None,
+            params: Some(params.as_slice()),
+            query_plan: Some(&query_plan),
+        });
+    }
+
+    async fn run_sql<TResult: Send + 'static>(
+        &self,
+        request: SqlRequest<'_>,
+        sql_data: SqlData,
+        rows_count: fn(&TResult) -> usize,
+        func: impl FnOnce(
+                &Connection,
+                &SqlData,
+            ) -> Result<Result<TResult, SqlLiteError>, async_sqlite::rusqlite::Error>
+            + Send
+            + 'static,
+    ) -> Result<TResult, SqlLiteError> {
+        let sql_data = Arc::new(sql_data);
+        let sql_data_spawned = sql_data.clone();
+
+        let started = Instant::now();
+        #[cfg(feature = "with-logs-and-telemetry")]
+        let started_at = DateTimeAsMicroseconds::now();
+
+        let wait_time = Arc::new(SqlWaitTime::new());
+        let wait_time_spawned = wait_time.clone();
+
+        let queue_guard = self.metrics.enter();
+
+        let interrupt_guard = InterruptGuard::new(self.interrupt_handle.clone());
+        let execution_state = interrupt_guard.get_state();
+
+        let future = self.client.conn(move |conn| {
+            wait_time_spawned.set_started(started);
+            execution_state.start()?;
+            let result = func(conn, &sql_data_spawned);
+            execution_state.finish();
+            result
+        });
+
+        let result = match self.query_timeout {
+            Some(query_timeout) => match tokio::time::timeout(query_timeout, future).await {
+                Ok(result) => result.map_err(SqlLiteError::from),
+                Err(_) => Err(SqlLiteError::Timeout { sql: None }),
+            },
+            None => future.await.map_err(SqlLiteError::from),
+        };
+
+        drop(interrupt_guard);
+        drop(queue_guard);
+
+        let result = match result {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(err)) => Err(self.attach_sql(err, &sql_data.sql)),
+            Err(err) => Err(self.attach_sql(err, &sql_data.sql)),
+        };
+
+        let duration = started.elapsed();
+
+        self.metrics.record(SqlMetricsRecord {
+            table_name: request.table_name,
+            operation: request.operation,
+            duration,
+            wait_time: wait_time.get(duration),
+            rows: result.as_ref().ok().map(rows_count),
+        });
+
+        self.write_log(&request, &sql_data, duration, &result, rows_count);
+
+        if let Some(slow_query_threshold) = self.slow_query_threshold {
+            if duration >= slow_query_threshold {
+                self.write_slow_query_log(&request, sql_data.clone(), duration)
+                    .await;
+            }
+        }
+
+        #[cfg(feature = "with-logs-and-telemetry")]
+        request.write_telemetry(
+            self.telemetry_sink.as_ref(),
+            started_at,
+            &result,
+            rows_count,
+        );
+
+        result
+    }
+
+    async fn execute_sql(
+        &self,
+        request: SqlRequest<'_>,
+        sql_data: SqlData,
+    ) -> Result<usize, SqlLiteError> {
+        self.run_sql(
+            request,
+            sql_data,
+            |rows: &usize| *rows,
+            |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                let result = stmt.execute(sql_data.values.get_params_to_invoke().as_slice())?;
+
+                Ok(Ok(result))
+            },
+        )
+        .await
+    }
+
+    async fn execute_sql_batch(
+        &self,
+        request: SqlRequest<'_>,
+        mut sql_batch: Vec<SqlData>,
+    ) -> Result<usize, SqlLiteError> {
+        if sql_batch.len() == 1 {
+            return self.execute_sql(request, sql_batch.remove(0)).await;
+        }
+
+        let sql_data = sql_batch.remove(0);
+
+        self.run_sql(
+            request,
+            sql_data,
+            |rows: &usize| *rows,
+            move |conn, sql_data| {
+                let transaction = conn.unchecked_transaction()?;
+
+                let mut result = 0;
+
+                for sql_data in std::iter::once(sql_data).chain(sql_batch.iter()) {
+                    let mut stmt = transaction.prepare_cached(&sql_data.sql)?;
+                    result += stmt.execute(sql_data.values.get_params_to_invoke().as_slice())?;
+                }
+
+                transaction.commit()?;
+
+                Ok(Ok(result))
+            },
+        )
+        .await
     }
 
     pub async fn create_table_if_not_exists<T: TableSchemaProvider>(
@@ -38,9 +328,16 @@ impl SqlLiteConnection {
     ) -> Result<(), SqlLiteError> {
         let crate_table_sql = crate::crate_table::generate_sql_request::<T>(table_name);
 
-        self.client
-            .conn(move |connection| connection.execute(crate_table_sql.as_str(), []))
-            .await?;
+        self.execute_sql(
+            SqlRequest {
+                operation: SqlOperation::CreateTable,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context: None,
+            },
+            SqlData::new(crate_table_sql, SqlValues::Empty),
+        )
+        .await?;
 
         if let Some(indexes) = T::get_indexes() {
             for (name, index_schema) in indexes {
@@ -50,9 +347,16 @@ impl SqlLiteConnection {
                     index_schema,
                 );
 
-                self.client
-                    .conn(move |connection| connection.execute(index_sql.as_str(), []))
-                    .await?;
+                self.execute_sql(
+                    SqlRequest {
+                        operation: SqlOperation::CreateTable,
+                        table_name,
+                        #[cfg(feature = "with-logs-and-telemetry")]
+                        telemetry_context: None,
+                    },
+                    SqlData::new(index_sql, SqlValues::Empty),
+                )
+                .await?;
             }
         }
 
@@ -72,34 +376,16 @@ impl SqlLiteConnection {
             &mut UsedColumns::as_none(),
         );
 
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
-
-        let sql_data = Arc::new(sql_data);
-
-        let sql_data_spawned = sql_data.clone();
-
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
-
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
-
-        let result = result?;
-
-        Ok(result)
+        self.execute_sql(
+            SqlRequest {
+                operation: SqlOperation::Insert,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await
     }
 
     pub async fn insert_db_entity_if_not_exists<TEntity: SqlInsertModel>(
@@ -115,32 +401,16 @@ impl SqlLiteConnection {
             &mut UsedColumns::as_none(),
         );
 
-        let sql_data = Arc::new(sql_data);
-
-        let sql_data_spawned = sql_data.clone();
-
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
-
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
-
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
-
-        Ok(result?)
+        self.execute_sql(
+            SqlRequest {
+                operation: SqlOperation::InsertIfNotExists,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await
     }
 
     pub async fn insert_or_update_db_entity<'s, TEntity: SqlInsertModel + SqlUpdateModel>(
@@ -151,32 +421,36 @@ impl SqlLiteConnection {
     ) -> Result<usize, SqlLiteError> {
         let sql_data = crate::sql::build_insert_or_update_sql(entity, table_name);
 
-        let sql_data = Arc::new(sql_data);
-
-        let sql_data_spawned = sql_data.clone();
-
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
-
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
-
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
+        self.execute_sql(
+            SqlRequest {
+                operation: SqlOperation::InsertOrUpdate,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await
+    }
 
-        Ok(result?)
+    pub async fn update_db_entity<TEntity: SqlUpdateModel + SqlWhereModel>(
+        &self,
+        entity: &TEntity,
+        table_name: &str,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<usize, SqlLiteError> {
+        let sql_data = crate::sql::build_update_sql(entity, table_name);
+
+        self.execute_sql(
+            SqlRequest {
+                operation: SqlOperation::Update,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await
     }
 
     pub async fn bulk_insert_db_entities<TEntity: SqlInsertModel>(
@@ -190,35 +464,23 @@ impl SqlLiteConnection {
         }
 
         let used_columns = entities[0].get_insert_columns_list();
-        let sql_data = Arc::new(crate::sql::build_bulk_insert_sql(
+        let sql_batch = crate::sql::build_bulk_insert_sql_chunks(
             crate::sql::InsertType::JustInsert,
             entities,
             table_name,
             &used_columns,
-        ));
-
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
-
-        let sql_data_spawned = sql_data.clone();
-
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
+        );
 
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
+        self.execute_sql_batch(
+            SqlRequest {
+                operation: SqlOperation::BulkInsert,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_batch,
+        )
+        .await?;
 
         Ok(())
     }
@@ -229,32 +491,18 @@ impl SqlLiteConnection {
         table_name: &str,
         #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
     ) -> Result<(), SqlLiteError> {
-        let sql_data = crate::sql::build_bulk_insert_or_update_sql(table_name, entities);
-
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
-
-        let sql_data = Arc::new(sql_data);
-
-        let sql_data_spawned = sql_data.clone();
-
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
-
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
+        let sql_batch = crate::sql::build_bulk_insert_or_update_sql_chunks(table_name, entities);
+
+        self.execute_sql_batch(
+            SqlRequest {
+                operation: SqlOperation::BulkInsertOrUpdate,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_batch,
+        )
+        .await?;
 
         Ok(())
     }
@@ -270,97 +518,153 @@ impl SqlLiteConnection {
         }
 
         let used_columns = entities[0].get_insert_columns_list();
-        let sql_data = Arc::new(crate::sql::build_bulk_insert_sql(
+        let sql_batch = crate::sql::build_bulk_insert_sql_chunks(
             crate::sql::InsertType::OrIgnore,
             entities,
             table_name,
             &used_columns,
-        ));
+        );
 
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
+        self.execute_sql_batch(
+            SqlRequest {
+                operation: SqlOperation::BulkInsertIfNotExists,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_batch,
+        )
+        .await?;
 
-        let sql_data_spawned = sql_data.clone();
+        Ok(())
+    }
 
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
+    pub async fn query_rows<
+        TEntity: SelectEntity + Send + Sync + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Vec<TEntity>, SqlLiteError> {
+        let request = SqlRequest {
+            operation: SqlOperation::Select,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
+        let sql_data = self
+            .select_sql_cache
+            .build_select_sql::<TEntity, TWhereModel>(table_name, where_model);
 
-        Ok(())
+        let result = self.query_decoded_rows(request, sql_data, false).await?;
+
+        Ok(result.items)
     }
 
-    pub async fn query_rows<
+    pub async fn query_rows_with_having<
         TEntity: SelectEntity + Send + Sync + 'static,
         TWhereModel: SqlWhereModel,
+        THavingModel: SqlHavingModel,
     >(
         &self,
         table_name: &str,
         where_model: Option<&TWhereModel>,
+        having_model: Option<&THavingModel>,
         #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
     ) -> Result<Vec<TEntity>, SqlLiteError> {
-        let select_builder = SelectBuilder::from_select_model::<TEntity>();
+        let request = SqlRequest {
+            operation: SqlOperation::Select,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-        let mut sql = String::new();
+        let sql_data = self
+            .select_sql_cache
+            .build_select_with_having_sql::<TEntity, TWhereModel, THavingModel>(
+                table_name,
+                where_model,
+                having_model,
+            );
 
-        let mut sql_values = SqlValues::new();
+        let result = self.query_decoded_rows(request, sql_data, false).await?;
 
-        select_builder.build_select_sql(&mut sql, &mut sql_values, table_name, where_model);
+        Ok(result.items)
+    }
+
+    pub async fn query_rows_skipping_bad_rows<
+        TEntity: SelectEntity + Send + Sync + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<DecodedRows<TEntity>, SqlLiteError> {
+        let request = SqlRequest {
+            operation: SqlOperation::Select,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-        let sql = Arc::new(sql);
+        let sql_data = self
+            .select_sql_cache
+            .build_select_sql::<TEntity, TWhereModel>(table_name, where_model);
 
-        if self.is_debug() {
-            println!("Sql: {}", sql);
-        }
+        self.query_decoded_rows(request, sql_data, true).await
+    }
 
-        let sql_spawned = sql.clone();
+    async fn query_decoded_rows<TEntity: SelectEntity + Send + Sync + 'static>(
+        &self,
+        request: SqlRequest<'_>,
+        sql_data: SqlData,
+        skip_bad_rows: bool,
+    ) -> Result<DecodedRows<TEntity>, SqlLiteError> {
+        self.run_sql(
+            request,
+            sql_data,
+            |rows: &DecodedRows<TEntity>| rows.items.len(),
+            move |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
 
-        let result = self
-            .client
-            .conn(move |conn| {
-                let mut stmt = conn.prepare(&sql_spawned)?;
+                let mut rows = stmt.query(sql_data.values.get_params_to_invoke().as_slice())?;
 
-                let response =
-                    stmt.query_map(sql_values.get_params_to_invoke().as_slice(), |row| {
-                        let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
-                        TEntity::from(&db_row);
-                        Ok(TEntity::from(&db_row))
-                    })?;
+                let mut result = DecodedRows::new();
 
-                let mut result = Vec::new();
+                let mut row_no = 0;
 
-                for itm in response {
-                    let itm = itm?;
-                    result.push(itm);
-                }
+                while let Some(row) = rows.next()? {
+                    let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
 
-                Ok(result)
-            })
-            .await;
+                    match TEntity::try_from_db_row(&db_row) {
+                        Ok(item) => result.items.push(item),
+                        Err(err) => {
+                            let err = err.into_sql_lite_error(row_no);
 
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql);
-            }
-        }
+                            if !skip_bad_rows {
+                                return Ok(Err(err));
+                            }
+
+                            result.errors.push(err);
+                        }
+                    }
+
+                    row_no += 1;
+                }
 
-        Ok(result?)
+                Ok(Ok(result))
+            },
+        )
+        .await
     }
 
+    // The connection query_timeout is not applied to streams: rows are pulled by the consumer,
+    // so the stream lives as long as the consumer needs. Wrap the consumer into with_timeout
+    // or drop the stream to interrupt the query.
     pub async fn query_rows_as_stream<
         TEntity: SelectEntity + Send + Sync + 'static,
         TWhereModel: SqlWhereModel + Send + Sync + 'static,
@@ -372,150 +676,622 @@ impl SqlLiteConnection {
     ) -> SqliteQueryStream<TEntity> {
         let select_builder = SelectBuilder::from_select_model::<TEntity>();
 
-        /*
-               let select_fields = TEntity::get_select_fields();
-
-               let mut sql = String::new();
-
-               let mut sql_values = SqlValues::new();
-
-               select_builder.build_select_sql(&mut sql, &mut sql_values, table_name, where_model);
-
-               let sql = Arc::new(sql);
-
-               if self.is_debug() {
-                   println!("Sql: {}", sql);
-               }
-
-        let sql_spawned = sql.clone();
-        */
         SqliteQueryStream::new::<TWhereModel>(
             self.client.clone(),
             table_name.into(),
             select_builder,
             where_model,
+            QueryStreamReporter {
+                logger: self.logger.clone(),
+                log_level: self.log_level,
+                metrics: self.metrics.clone(),
+                redact_sql_in_errors: self.redact_sql_in_errors,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_sink: self.telemetry_sink.clone(),
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context: telemetry_context.cloned(),
+            },
+            self.interrupt_handle.clone(),
+            self.stream_channel_capacity,
         )
     }
-    pub async fn query_single_row<
+
+    pub async fn query_page<
         TEntity: SelectEntity + Send + Sync + 'static,
         TWhereModel: SqlWhereModel,
     >(
         &self,
         table_name: &str,
         where_model: Option<&TWhereModel>,
+        page: usize,
+        page_size: usize,
         #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
-    ) -> Result<Option<TEntity>, SqlLiteError> {
-        let select_builder = SelectBuilder::from_select_model::<TEntity>();
+    ) -> Result<PagedResult<TEntity>, SqlLiteError> {
+        if page == 0 {
+            return Err(SqlLiteError::InvalidArgument {
+                message: "Pages are numbered from 1. Attempt to query_page with page 0"
+                    .to_string(),
+            });
+        }
 
-        let mut sql = String::new();
+        if page_size == 0 {
+            return Err(SqlLiteError::InvalidArgument {
+                message: "query_page requires page_size greater than 0".to_string(),
+            });
+        }
 
-        #[cfg(test)]
-        println!("Sql: {}", sql);
+        let offset = (page - 1)
+            .checked_mul(page_size)
+            .and_then(|offset| i64::try_from(offset).ok());
 
-        let mut sql_values = SqlValues::new();
+        let (Some(offset), Ok(limit)) = (offset, i64::try_from(page_size)) else {
+            return Err(SqlLiteError::InvalidArgument {
+                message: format!(
+                    "Attempt to query_page with page {} and page_size {} overflows the offset",
+                    page, page_size
+                ),
+            });
+        };
 
-        select_builder.build_select_sql(&mut sql, &mut sql_values, table_name, where_model);
+        let count_sql = self
+            .select_sql_cache
+            .build_count_page_sql::<TEntity, TWhereModel>(table_name, where_model);
 
-        let sql = Arc::new(sql);
+        let sql_data = self.select_sql_cache.build_select_page_sql::<TEntity, TWhereModel>(
+            table_name,
+            where_model,
+            limit,
+            offset,
+        );
 
-        if self.is_debug() {
-            println!("Sql: {}", sql);
+        let request = SqlRequest {
+            operation: SqlOperation::SelectPage,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
+
+        let (items, total) = self
+            .run_sql(
+                request,
+                sql_data,
+                |(items, _): &(Vec<TEntity>, u64)| items.len(),
+                move |conn, sql_data| {
+                    let transaction = conn.unchecked_transaction()?;
+
+                    let total: i64 = transaction.prepare_cached(&count_sql.sql)?.query_row(
+                        count_sql.values.get_params_to_invoke().as_slice(),
+                        |row| row.get(0),
+                    )?;
+
+                    let mut items = Vec::new();
+
+                    {
+                        let mut stmt = transaction.prepare_cached(&sql_data.sql)?;
+
+                        let mut rows =
+                            stmt.query(sql_data.values.get_params_to_invoke().as_slice())?;
+
+                        while let Some(row) = rows.next()? {
+                            let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
+
+                            match TEntity::try_from_db_row(&db_row) {
+                                Ok(item) => items.push(item),
+                                Err(err) => {
+                                    return Ok(Err(err.into_sql_lite_error(items.len())))
+                                }
+                            }
+                        }
+                    }
+
+                    transaction.commit()?;
+
+                    Ok(Ok((items, total as u64)))
+                },
+            )
+            .await?;
+
+        Ok(PagedResult {
+            items,
+            total,
+            page,
+            page_size,
+        })
+    }
+
+    pub async fn query_page_by_cursor<
+        TEntity: CursorPageEntity + TableSchemaProvider + Send + Sync + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        cursor: Option<&str>,
+        page_size: usize,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Page<TEntity>, SqlLiteError> {
+        if page_size == 0 {
+            return Err(SqlLiteError::InvalidArgument {
+                message: "query_page_by_cursor requires page_size greater than 0".to_string(),
+            });
         }
 
-        let sql_spawned = sql.clone();
+        let Some(limit) = page_size.checked_add(1) else {
+            return Err(SqlLiteError::InvalidArgument {
+                message: format!("page_size {} is too large for query_page_by_cursor", page_size),
+            });
+        };
 
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.query_row_and_then(
-                    &sql_spawned,
-                    sql_values.get_params_to_invoke().as_slice(),
-                    |row| {
+        let Some(order_by) = TEntity::get_order_by_fields() else {
+            return Err(SqlLiteError::InvalidArgument {
+                message: "query_page_by_cursor requires an entity with order_by fields"
+                    .to_string(),
+            });
+        };
+
+        let mut page_cursor = PageCursor::from_order_by(order_by);
+        page_cursor.check_not_nullable(TEntity::get_columns().as_slice())?;
+
+        if let Some(primary_key_columns) = TEntity::get_primary_key_columns() {
+            page_cursor.append_primary_key(primary_key_columns.as_slice());
+        }
+
+        let last_key = match cursor {
+            Some(cursor) => Some(page_cursor.decode(cursor)?),
+            None => None,
+        };
+
+        let sql_data = page_cursor.build_select_sql::<TEntity, TWhereModel>(
+            table_name,
+            where_model,
+            last_key.as_deref(),
+            limit,
+        );
+
+        let key_columns_count = page_cursor.len();
+
+        let request = SqlRequest {
+            operation: SqlOperation::SelectPage,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
+
+        let (mut items, last_key) = self
+            .run_sql(
+                request,
+                sql_data,
+                |(items, _): &(Vec<TEntity>, Option<Vec<Value>>)| items.len(),
+                move |conn, sql_data| {
+                    let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                    let mut rows =
+                        stmt.query(sql_data.values.get_params_to_invoke().as_slice())?;
+
+                    let mut items = Vec::new();
+                    let mut last_key = None;
+
+                    while let Some(row) = rows.next()? {
                         let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
-                        TEntity::from(&db_row);
-                        Ok(TEntity::from(&db_row))
-                    },
-                )
-            })
-            .await;
-
-        if let Err(err) = &result {
-            match err {
-                async_sqlite::Error::Rusqlite(err) => match err {
-                    async_sqlite::rusqlite::Error::QueryReturnedNoRows => {
-                        return Ok(None);
+
+                        match TEntity::try_from_db_row(&db_row) {
+                            Ok(item) => items.push(item),
+                            Err(err) => return Ok(Err(err.into_sql_lite_error(items.len()))),
+                        }
+
+                        if items.len() == page_size {
+                            last_key = Some(crate::read_row_key(
+                                row,
+                                TEntity::SELECT_FIELDS.len(),
+                                key_columns_count,
+                            )?);
+                        }
                     }
 
-                    _ => {}
+                    Ok(Ok((items, last_key)))
                 },
-                _ => {}
+            )
+            .await?;
+
+        let next_cursor = if items.len() > page_size {
+            items.truncate(page_size);
+            last_key.map(|last_key| PageCursor::encode(last_key.as_slice()))
+        } else {
+            None
+        };
+
+        Ok(Page { items, next_cursor })
+    }
+
+    pub fn query_rows_as_chunks<
+        's,
+        TEntity: SelectEntity + TableSchemaProvider + Send + Sync + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &'s self,
+        table_name: &'s str,
+        where_model: Option<&'s TWhereModel>,
+        chunk_size: usize,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<
+            &'s MyTelemetryContext,
+        >,
+    ) -> Result<SqlChunksReader<'s, TEntity, TWhereModel>, SqlLiteError> {
+        let request = SqlRequest {
+            operation: SqlOperation::SelectChunk,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
+
+        SqlChunksReader::new(self, request, where_model, chunk_size)
+    }
+
+    pub(crate) async fn query_chunk<TEntity: SelectEntity + Send + Sync + 'static>(
+        &self,
+        request: SqlRequest<'_>,
+        sql_data: SqlData,
+        primary_key_columns_count: usize,
+    ) -> Result<(Vec<TEntity>, Option<Vec<Value>>), SqlLiteError> {
+        self.run_sql(
+            request,
+            sql_data,
+            |(items, _): &(Vec<TEntity>, Option<Vec<Value>>)| items.len(),
+            move |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                let mut rows = stmt.query(sql_data.values.get_params_to_invoke().as_slice())?;
+
+                let mut items = Vec::new();
+                let mut last_key = None;
+
+                while let Some(row) = rows.next()? {
+                    let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
+
+                    match TEntity::try_from_db_row(&db_row) {
+                        Ok(item) => items.push(item),
+                        Err(err) => return Ok(Err(err.into_sql_lite_error(items.len()))),
+                    }
+
+                    last_key = Some(crate::read_row_key(
+                        row,
+                        TEntity::SELECT_FIELDS.len(),
+                        primary_key_columns_count,
+                    )?);
+                }
+
+                Ok(Ok((items, last_key)))
+            },
+        )
+        .await
+    }
+
+    pub async fn query_single_row<
+        TEntity: SelectEntity + Send + Sync + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Option<TEntity>, SqlLiteError> {
+        let sql_data = self
+            .select_sql_cache
+            .build_select_sql::<TEntity, TWhereModel>(table_name, where_model);
+
+        self.run_sql(
+            SqlRequest {
+                operation: SqlOperation::SelectSingle,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+            |row: &Option<TEntity>| if row.is_some() { 1 } else { 0 },
+            |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                let result = stmt
+                    .query_row(sql_data.values.get_params_to_invoke().as_slice(), |row| {
+                        let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
+                        Ok(TEntity::try_from_db_row(&db_row)
+                            .map_err(|err| err.into_sql_lite_error(0)))
+                    })
+                    .optional()?;
+
+                Ok(result.transpose())
+            },
+        )
+        .await
+    }
+
+    pub async fn get_count<TWhereModel: SqlWhereModel, TResult: CountResult + Send + 'static>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<TResult, SqlLiteError> {
+        let sql_data = crate::sql::build_count_sql(table_name, where_model);
+
+        self.query_count(
+            SqlRequest {
+                operation: SqlOperation::Count,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await
+    }
+
+    pub async fn count_distinct<
+        TWhereModel: SqlWhereModel,
+        TResult: CountResult + Send + 'static,
+    >(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<TResult, SqlLiteError> {
+        let sql_data = crate::sql::build_count_distinct_sql(table_name, column_name, where_model);
+
+        self.query_count(
+            SqlRequest {
+                operation: SqlOperation::Count,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await
+    }
+
+    async fn query_count<TResult: CountResult + Send + 'static>(
+        &self,
+        request: SqlRequest<'_>,
+        sql_data: SqlData,
+    ) -> Result<TResult, SqlLiteError> {
+        self.run_sql(request, sql_data, |_: &TResult| 1, |conn, sql_data| {
+            let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+            let result: i64 = stmt.query_row(
+                sql_data.values.get_params_to_invoke().as_slice(),
+                |row| row.get(0),
+            )?;
+
+            match TResult::from_sqlite_count("count", result) {
+                Ok(result) => Ok(Ok(result)),
+                Err(err) => Ok(Err(err.into_sql_lite_error(0))),
             }
+        })
+        .await
+    }
 
-            println!("Sql: {}", sql.as_str());
-        }
+    pub async fn count_grouped_by<TWhereModel: SqlWhereModel, TKey: FromSql + Send + 'static>(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Vec<(TKey, u64)>, SqlLiteError> {
+        let sql_data = crate::sql::build_count_grouped_by_sql(table_name, column_name, where_model);
+
+        self.run_sql(
+            SqlRequest {
+                operation: SqlOperation::Count,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+            |items: &Vec<(TKey, u64)>| items.len(),
+            |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                let mut rows = stmt.query(sql_data.values.get_params_to_invoke().as_slice())?;
 
-        let value = result?;
+                let mut result = Vec::new();
 
-        Ok(Some(value))
+                while let Some(row) = rows.next()? {
+                    let count: i64 = row.get(1)?;
+
+                    let count = match u64::from_sqlite_count("count", count) {
+                        Ok(count) => count,
+                        Err(err) => return Ok(Err(err.into_sql_lite_error(result.len()))),
+                    };
+
+                    result.push((row.get(0)?, count));
+                }
+
+                Ok(Ok(result))
+            },
+        )
+        .await
     }
 
-    pub async fn get_count<
+    /// DateTimeAsMicroseconds columns stored as ISO text are compared as text: the result is
+    /// chronological only while every value is written in the same format and offset.
+    pub async fn max<
+        TResult: AggregateFieldType + Send + 'static,
         TWhereModel: SqlWhereModel,
-        TResult: CountResult + FromSql + Send + Sync + 'static,
     >(
         &self,
         table_name: &str,
+        column_name: &str,
         where_model: Option<&TWhereModel>,
         #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
     ) -> Result<Option<TResult>, SqlLiteError> {
-        let mut sql = String::new();
+        let request = SqlRequest {
+            operation: SqlOperation::Aggregate,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-        let mut sql_values = SqlValues::new();
-        sql.push_str("SELECT COUNT(*)::");
-        sql.push_str(TResult::get_postgres_type());
+        self.query_aggregate(request, "MAX", column_name, where_model).await
+    }
 
-        sql.push_str(" FROM ");
-        sql.push_str(table_name);
+    /// DateTimeAsMicroseconds columns stored as ISO text are compared as text: the result is
+    /// chronological only while every value is written in the same format and offset.
+    pub async fn min<
+        TResult: AggregateFieldType + Send + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Option<TResult>, SqlLiteError> {
+        let request = SqlRequest {
+            operation: SqlOperation::Aggregate,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-        if self.is_debug() {
-            println!("Sql: {}", sql);
-        }
+        self.query_aggregate(request, "MIN", column_name, where_model).await
+    }
 
-        if let Some(where_model) = where_model {
-            if where_model.has_conditions() {
-                sql.push_str(" WHERE ");
-                where_model.fill_where_component(&mut sql, &mut sql_values);
-            }
+    pub async fn sum<
+        TResult: AggregateFieldType + Send + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Option<TResult>, SqlLiteError> {
+        let request = SqlRequest {
+            operation: SqlOperation::Aggregate,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-            where_model.fill_limit_and_offset(&mut sql);
-        }
+        self.query_aggregate(request, "SUM", column_name, where_model).await
+    }
 
-        let sql = Arc::new(sql);
+    pub async fn avg<
+        TResult: AggregateFieldType + Send + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<Option<TResult>, SqlLiteError> {
+        let request = SqlRequest {
+            operation: SqlOperation::Aggregate,
+            table_name,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_context,
+        };
 
-        let sql_spawned = sql.clone();
+        self.query_aggregate(request, "AVG", column_name, where_model).await
+    }
 
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.query_row_and_then(
-                    &sql_spawned,
-                    sql_values.get_params_to_invoke().as_slice(),
-                    |row| {
-                        let result = row.get(0)?;
-                        Ok(result)
-                    },
-                )
-            })
-            .await;
+    async fn query_aggregate<
+        TResult: AggregateFieldType + Send + 'static,
+        TWhereModel: SqlWhereModel,
+    >(
+        &self,
+        request: SqlRequest<'_>,
+        function: &str,
+        column_name: &str,
+        where_model: Option<&TWhereModel>,
+    ) -> Result<Option<TResult>, SqlLiteError> {
+        let statement = TResult::render_aggregate_statement(
+            function,
+            crate::quote_identifier(column_name).as_str(),
+        );
 
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql);
-            }
-        }
+        let sql_data =
+            crate::sql::build_aggregate_sql(request.table_name, statement.as_str(), where_model);
+
+        let column_name = column_name.to_string();
+
+        self.run_sql(
+            request,
+            sql_data,
+            |result: &Option<TResult>| if result.is_some() { 1 } else { 0 },
+            move |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                let value: Value = stmt.query_row(
+                    sql_data.values.get_params_to_invoke().as_slice(),
+                    |row| row.get(0),
+                )?;
+
+                match TResult::try_from_aggregate_value(column_name.as_str(), value) {
+                    Ok(result) => Ok(Ok(result)),
+                    Err(err) => Ok(Err(err.into_sql_lite_error(0))),
+                }
+            },
+        )
+        .await
+    }
+
+    pub async fn exists<TWhereModel: SqlWhereModel>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+        #[cfg(feature = "with-logs-and-telemetry")] telemetry_context: Option<&MyTelemetryContext>,
+    ) -> Result<bool, SqlLiteError> {
+        let sql_data = crate::sql::build_exists_sql(table_name, where_model);
+
+        self.run_sql(
+            SqlRequest {
+                operation: SqlOperation::Exists,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+            |_: &bool| 1,
+            |conn, sql_data| {
+                let mut stmt = conn.prepare_cached(&sql_data.sql)?;
+
+                let result: bool = stmt.query_row(
+                    sql_data.values.get_params_to_invoke().as_slice(),
+                    |row| row.get(0),
+                )?;
+
+                Ok(Ok(result))
+            },
+        )
+        .await
+    }
+
+    pub async fn explain<TEntity: SelectEntity, TWhereModel: SqlWhereModel>(
+        &self,
+        table_name: &str,
+        where_model: Option<&TWhereModel>,
+    ) -> Result<QueryPlan, SqlLiteError> {
+        let select_builder = SelectBuilder::from_select_model::<TEntity>();
+
+        let mut sql = String::new();
+
+        let mut sql_values = SqlValues::new();
+
+        select_builder.build_select_sql(&mut sql, &mut sql_values, table_name, where_model);
 
-        Ok(result?)
+        self.run_sql(
+            SqlRequest {
+                operation: SqlOperation::Explain,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context: None,
+            },
+            SqlData::new(sql, sql_values),
+            |plan: &QueryPlan| plan.steps.len(),
+            |conn, sql_data| QueryPlan::read(conn, sql_data).map(Ok),
+        )
+        .await
     }
 
     pub async fn delete_db_entity<TWhereModel: SqlWhereModel>(
@@ -527,40 +1303,44 @@ impl SqlLiteConnection {
     ) -> Result<(), SqlLiteError> {
         let sql_data = where_model.build_delete_sql(table_name);
 
-        if self.is_debug() {
-            println!("Sql: {}", sql_data.sql);
-        }
-
-        let sql_data = Arc::new(sql_data);
-
-        let sql_data_spawned = sql_data.clone();
-
-        let result = self
-            .client
-            .conn(move |conn| {
-                conn.execute(
-                    &sql_data_spawned.sql,
-                    sql_data_spawned.values.get_params_to_invoke().as_slice(),
-                )
-            })
-            .await;
-
-        if let Err(err) = &result {
-            println!("Err: {}", err);
-            if self.is_debug() {
-                println!("Sql: {}", sql_data.sql);
-            }
-        }
+        self.execute_sql(
+            SqlRequest {
+                operation: SqlOperation::Delete,
+                table_name,
+                #[cfg(feature = "with-logs-and-telemetry")]
+                telemetry_context,
+            },
+            sql_data,
+        )
+        .await?;
 
         Ok(())
     }
 
     // Connection can not be reused after
     pub async fn close(&self) {
+        let started = Instant::now();
         let close_result = self.client.close().await;
 
-        if let Err(close_result) = close_result {
-            println!("Error closing sqlite connection: {}", close_result);
+        if let Err(err) = close_result {
+            if !self.log_level.is_enabled(SqlLogLevel::Error) {
+                return;
+            }
+
+            let err = SqlLiteError::from(err);
+
+            self.logger.log(&SqlLogEvent {
+                level: SqlLogLevel::Error,
+                operation: SqlOperation::Close,
+                table_name: "",
+                sql: "",
+                params_count: 0,
+                duration: started.elapsed(),
+                rows: None,
+                error: Some(&err),
+                params: None,
+                query_plan: None,
+            });
         }
     }
 }
@@ -1,15 +1,31 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use async_sqlite::{ClientBuilder, JournalMode};
 use rust_extensions::StrOrString;
 
-use crate::{table_schema::TableSchemaProvider, SqlLiteConnection, SqlLiteError};
+use crate::{
+    table_schema::TableSchemaProvider, ConsoleLogger, SelectSqlCache, SqlLiteConnection,
+    SqlLiteError, SqlLiteLogger, SqlLogLevel, SqlMetrics, DEFAULT_STATEMENT_CACHE_CAPACITY,
+    DEFAULT_STREAM_CHANNEL_CAPACITY,
+};
+#[cfg(feature = "with-logs-and-telemetry")]
+use crate::SqlTelemetrySink;
 
 pub struct SqlLiteConnectionBuilder {
     path: StrOrString<'static>,
     create_table_sql: Vec<String>,
 
     debug: bool,
+    redact_sql_in_errors: bool,
+    logger: Option<Arc<dyn SqlLiteLogger>>,
+    log_level: Option<SqlLogLevel>,
+    slow_query_threshold: Option<Duration>,
+    metrics: Option<Arc<SqlMetrics>>,
+    statement_cache_capacity: usize,
+    query_timeout: Option<Duration>,
+    stream_channel_capacity: usize,
+    #[cfg(feature = "with-logs-and-telemetry")]
+    telemetry_sink: Option<Arc<dyn SqlTelemetrySink>>,
 }
 
 impl SqlLiteConnectionBuilder {
@@ -18,6 +34,16 @@ impl SqlLiteConnectionBuilder {
             path: path.into(),
             create_table_sql: Vec::with_capacity(4),
             debug: false,
+            redact_sql_in_errors: false,
+            logger: None,
+            log_level: None,
+            slow_query_threshold: None,
+            metrics: None,
+            statement_cache_capacity: DEFAULT_STATEMENT_CACHE_CAPACITY,
+            query_timeout: None,
+            stream_channel_capacity: DEFAULT_STREAM_CHANNEL_CAPACITY,
+            #[cfg(feature = "with-logs-and-telemetry")]
+            telemetry_sink: None,
         }
     }
 
@@ -26,6 +52,53 @@ impl SqlLiteConnectionBuilder {
         self
     }
 
+    pub fn logger(mut self, logger: Arc<dyn SqlLiteLogger>) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    pub fn log_level(mut self, log_level: SqlLogLevel) -> Self {
+        self.log_level = Some(log_level);
+        self
+    }
+
+    #[cfg(feature = "with-logs-and-telemetry")]
+    pub fn telemetry_sink(mut self, telemetry_sink: Arc<dyn SqlTelemetrySink>) -> Self {
+        self.telemetry_sink = Some(telemetry_sink);
+        self
+    }
+
+    pub fn slow_query_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_query_threshold = Some(threshold);
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Arc<SqlMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn statement_cache_capacity(mut self, capacity: usize) -> Self {
+        self.statement_cache_capacity = capacity;
+        self
+    }
+
+    // Applies to every query except query_rows_as_stream
+    pub fn query_timeout(mut self, timeout: Duration) -> Self {
+        self.query_timeout = Some(timeout);
+        self
+    }
+
+    pub fn stream_channel_capacity(mut self, capacity: usize) -> Self {
+        self.stream_channel_capacity = capacity.max(1);
+        self
+    }
+
+    pub fn redact_sql_in_errors(mut self, value: bool) -> Self {
+        self.redact_sql_in_errors = value;
+        self
+    }
+
     pub fn create_table_if_no_exists<T: TableSchemaProvider>(mut self, table_name: &str) -> Self {
         self.create_table_sql
             .push(crate::crate_table::generate_sql_request::<T>(table_name));
@@ -44,12 +117,20 @@ impl SqlLiteConnectionBuilder {
         self
     }
 
-    fn is_debug(&self) -> bool {
-        self.debug || std::env::var("DEBUG").is_ok()
+    fn get_log_level(&self) -> SqlLogLevel {
+        if let Some(log_level) = self.log_level {
+            return log_level;
+        }
+
+        if self.debug || std::env::var("DEBUG").is_ok() {
+            SqlLogLevel::Debug
+        } else {
+            SqlLogLevel::Warn
+        }
     }
 
     pub async fn build(self) -> Result<SqlLiteConnection, SqlLiteError> {
-        let debug = self.is_debug();
+        let log_level = self.get_log_level();
         let client = ClientBuilder::new()
             .path(self.path.as_str())
             .journal_mode(JournalMode::Off)
@@ -57,7 +138,33 @@ impl SqlLiteConnectionBuilder {
             .await
             .unwrap();
 
-        let result = SqlLiteConnection::new(client, debug).await;
+        let mut result = SqlLiteConnection::new(client, self.debug).await;
+        result.redact_sql_in_errors = self.redact_sql_in_errors;
+        result.log_level = log_level;
+        result.slow_query_threshold = self.slow_query_threshold;
+        result.query_timeout = self.query_timeout;
+        result.stream_channel_capacity = self.stream_channel_capacity;
+
+        if let Some(metrics) = self.metrics {
+            result.metrics = metrics;
+        }
+
+        let statement_cache_capacity = self.statement_cache_capacity;
+        result.select_sql_cache = SelectSqlCache::new(statement_cache_capacity);
+
+        result
+            .client
+            .conn(move |connection| {
+                connection.set_prepared_statement_cache_capacity(statement_cache_capacity);
+                Ok(())
+            })
+            .await?;
+        result.logger = self.logger.unwrap_or_else(|| Arc::new(ConsoleLogger));
+
+        #[cfg(feature = "with-logs-and-telemetry")]
+        if let Some(telemetry_sink) = self.telemetry_sink {
+            result.telemetry_sink = telemetry_sink;
+        }
 
         for create_table_sql in self.create_table_sql {
             let create_table_sql = Arc::new(create_table_sql);
@@ -70,10 +177,6 @@ impl SqlLiteConnectionBuilder {
                 .await;
 
             if let Err(err) = &result {
-                if debug {
-                    println!("Sql:{}", create_table_sql.as_str());
-                }
-
                 let mut skip_error = false;
 
                 match err {
@@ -98,7 +201,7 @@ impl SqlLiteConnectionBuilder {
                 }
 
                 if !skip_error {
-                    panic!("{:?}", err);
+                    panic!("{:?}. Sql: {}", err, create_table_sql.as_str());
                 }
             }
         }
@@ -0,0 +1,91 @@
+#[cfg(feature = "with-logs-and-telemetry")]
+use my_telemetry::MyTelemetryContext;
+#[cfg(feature = "with-logs-and-telemetry")]
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::SqlOperation;
+#[cfg(feature = "with-logs-and-telemetry")]
+use crate::{SqlLiteError, SqlTelemetryEvent, SqlTelemetrySink};
+
+#[derive(Clone, Copy)]
+pub(crate) struct SqlRequest<'s> {
+    pub operation: SqlOperation,
+    pub table_name: &'s str,
+    #[cfg(feature = "with-logs-and-telemetry")]
+    pub telemetry_context: Option<&'s MyTelemetryContext>,
+}
+
+#[cfg(feature = "with-logs-and-telemetry")]
+impl<'s> SqlRequest<'s> {
+    pub fn write_telemetry<TResult>(
+        &self,
+        sink: &dyn SqlTelemetrySink,
+        started: DateTimeAsMicroseconds,
+        result: &Result<TResult, SqlLiteError>,
+        rows_count: fn(&TResult) -> usize,
+    ) {
+        let Some(telemetry_context) = self.telemetry_context else {
+            return;
+        };
+
+        sink.write(SqlTelemetryEvent {
+            telemetry_context: telemetry_context.clone(),
+            started,
+            finished: DateTimeAsMicroseconds::now(),
+            operation: self.operation,
+            table_name: self.table_name.to_string(),
+            rows: result.as_ref().ok().map(rows_count),
+            error: result.as_ref().err().map(|err| err.to_string()),
+        });
+    }
+}
+
+#[cfg(all(test, feature = "with-logs-and-telemetry"))]
+mod tests {
+    use my_telemetry::MyTelemetryContext;
+    use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+    use crate::{InMemoryTelemetrySink, SqlLiteError, SqlOperation};
+
+    use super::SqlRequest;
+
+    #[test]
+    fn test_events_are_written_only_with_context() {
+        let sink = InMemoryTelemetrySink::new();
+        let telemetry_context = MyTelemetryContext::new();
+
+        let request = SqlRequest {
+            operation: SqlOperation::Insert,
+            table_name: "items",
+            telemetry_context: None,
+        };
+
+        let result: Result<usize, SqlLiteError> = Ok(1);
+        request.write_telemetry(&sink, DateTimeAsMicroseconds::now(), &result, |rows| *rows);
+        assert!(sink.get_events().is_empty());
+
+        let request = SqlRequest {
+            operation: SqlOperation::Select,
+            table_name: "items",
+            telemetry_context: Some(&telemetry_context),
+        };
+
+        let result: Result<usize, SqlLiteError> = Ok(3);
+        request.write_telemetry(&sink, DateTimeAsMicroseconds::now(), &result, |rows| *rows);
+
+        let result: Result<usize, SqlLiteError> = Err(SqlLiteError::NotFound { sql: None });
+        request.write_telemetry(&sink, DateTimeAsMicroseconds::now(), &result, |rows| *rows);
+
+        let events = sink.get_events();
+        assert_eq!(2, events.len());
+
+        assert_eq!(SqlOperation::Select, events[0].operation);
+        assert_eq!("items", events[0].table_name);
+        assert_eq!(Some(3), events[0].rows);
+        assert!(events[0].is_success());
+        assert!(events[0].finished.unix_microseconds >= events[0].started.unix_microseconds);
+
+        assert!(!events[1].is_success());
+        assert_eq!(None, events[1].rows);
+    }
+}
@@ -3,299 +3,500 @@ use std::{
     hash::Hash,
 };
 
+use async_sqlite::rusqlite::types::Value;
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 use serde::de::DeserializeOwned;
 
-use crate::SqlValueMetadata;
+use crate::{DateTimeStorage, DecodeError, SqlValueMetadata};
 
 use super::DbColumnName;
 
 pub trait FromDbRow<'s, TResult> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<TResult, DecodeError>;
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<TResult>, DecodeError>;
+
     fn from_db_row(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         metadata: &Option<SqlValueMetadata>,
-    ) -> TResult;
+    ) -> TResult {
+        match Self::try_from_db_row(row, column_name, metadata) {
+            Ok(result) => result,
+            Err(err) => panic!("{}", err),
+        }
+    }
 
     fn from_db_row_opt(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         metadata: &Option<SqlValueMetadata>,
-    ) -> Option<TResult>;
+    ) -> Option<TResult> {
+        match Self::try_from_db_row_opt(row, column_name, metadata) {
+            Ok(result) => result,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+fn convert_integer<T: TryFrom<i64>>(
+    column_name: DbColumnName,
+    value: i64,
+    type_name: &str,
+) -> Result<T, DecodeError> {
+    match T::try_from(value) {
+        Ok(result) => Ok(result),
+        Err(_) => Err(DecodeError::invalid_value(
+            column_name.db_column_name,
+            format!("Value {} is out of range for {}", value, type_name),
+        )),
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(column_name: &str, value: &str) -> Result<T, DecodeError> {
+    match serde_json::from_str(value) {
+        Ok(result) => Ok(result),
+        Err(err) => Err(DecodeError::json(column_name, err)),
+    }
 }
 
 impl<'s> FromDbRow<'s, String> for String {
-    fn from_db_row(
-        row: &'s crate::DbRow,
+    fn try_from_db_row(
+        row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> String {
-        row.get(column_name.db_column_name)
+    ) -> Result<String, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<String> {
-        row.get(column_name.db_column_name)
+    ) -> Result<Option<String>, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 }
 
 impl<'s> FromDbRow<'s, i64> for i64 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> i64 {
-        row.get(column_name.db_column_name)
+    ) -> Result<i64, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<i64> {
-        row.get(column_name.db_column_name)
+    ) -> Result<Option<i64>, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 }
 
 impl<'s> FromDbRow<'s, u64> for u64 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> u64 {
-        let result: i64 = row.get(column_name.db_column_name);
-        result as u64
+    ) -> Result<u64, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "u64")
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<u64> {
-        let result: Option<i64> = row.get(column_name.db_column_name);
-        let result = result?;
-        Some(result as u64)
+    ) -> Result<Option<u64>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "u64")?)),
+            None => Ok(None),
+        }
     }
 }
 
 impl<'s> FromDbRow<'s, i32> for i32 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> i32 {
-        row.get(column_name.db_column_name)
+    ) -> Result<i32, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<i32> {
-        row.get(column_name.db_column_name)
+    ) -> Result<Option<i32>, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 }
 
 impl<'s> FromDbRow<'s, u32> for u32 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> u32 {
-        let result: i64 = row.get(column_name.db_column_name);
-        result as u32
+    ) -> Result<u32, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "u32")
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<u32>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "u32")?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> FromDbRow<'s, i16> for i16 {
+    fn try_from_db_row(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<i16, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "i16")
+    }
+
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<i16>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "i16")?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> FromDbRow<'s, u16> for u16 {
+    fn try_from_db_row(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<u16, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "u16")
+    }
+
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<u16>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "u16")?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> FromDbRow<'s, i8> for i8 {
+    fn try_from_db_row(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<i8, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "i8")
+    }
+
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<i8>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "i8")?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> FromDbRow<'s, u8> for u8 {
+    fn try_from_db_row(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<u8, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "u8")
+    }
+
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<u8>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "u8")?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> FromDbRow<'s, usize> for usize {
+    fn try_from_db_row(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<usize, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "usize")
+    }
+
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<usize>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "usize")?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'s> FromDbRow<'s, isize> for isize {
+    fn try_from_db_row(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<isize, DecodeError> {
+        let result: i64 = row.try_get(column_name.db_column_name)?;
+        convert_integer(column_name, result, "isize")
+    }
+
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<u32> {
-        let result: Option<i64> = row.get(column_name.db_column_name);
-        let result = result?;
-        Some(result as u32)
+    ) -> Result<Option<isize>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        match result {
+            Some(result) => Ok(Some(convert_integer(column_name, result, "isize")?)),
+            None => Ok(None),
+        }
     }
 }
 
 impl<'s> FromDbRow<'s, bool> for bool {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> bool {
-        row.get(column_name.db_column_name)
+    ) -> Result<bool, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<bool> {
-        row.get(column_name.db_column_name)
+    ) -> Result<Option<bool>, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 }
 
 impl<'s, T: DeserializeOwned> FromDbRow<'s, Vec<T>> for Vec<T> {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Vec<T> {
+    ) -> Result<Vec<T>, DecodeError> {
         let mut db_column_name = String::new();
         crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
-        let value: String = row.get(db_column_name.as_str());
-        serde_json::from_str(&value).unwrap()
+
+        let value: String = row.try_get(db_column_name.as_str())?;
+        parse_json(column_name.db_column_name, &value)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<Vec<T>> {
-        let value: Option<String> = row.get(column_name.db_column_name);
+    ) -> Result<Option<Vec<T>>, DecodeError> {
+        let mut db_column_name = String::new();
+        crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
 
-        let value = value.as_ref()?;
-        let result = serde_json::from_str(value).unwrap();
-        Some(result)
+        let value: Option<String> = row.try_get(db_column_name.as_str())?;
+        match value {
+            Some(value) => Ok(Some(parse_json(column_name.db_column_name, &value)?)),
+            None => Ok(None),
+        }
     }
 }
 
 impl<'s, TKey: DeserializeOwned + Eq + Hash, TValue: DeserializeOwned>
     FromDbRow<'s, HashMap<TKey, TValue>> for HashMap<TKey, TValue>
 {
-    fn from_db_row(
-        row: &crate::DbRow,
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> HashMap<TKey, TValue> {
+    ) -> Result<HashMap<TKey, TValue>, DecodeError> {
         let mut db_column_name = String::new();
         crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
 
-        let value: String = row.get(db_column_name.as_str());
-        serde_json::from_str(&value).unwrap()
+        let value: String = row.try_get(db_column_name.as_str())?;
+        parse_json(column_name.db_column_name, &value)
     }
 
-    fn from_db_row_opt(
-        row: &crate::DbRow,
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<HashMap<TKey, TValue>> {
+    ) -> Result<Option<HashMap<TKey, TValue>>, DecodeError> {
         let mut db_column_name = String::new();
         crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
 
-        let value: Option<String> = row.get(db_column_name.as_str());
-        let value = value.as_ref()?;
-        let result = serde_json::from_str(value).unwrap();
-        Some(result)
+        let value: Option<String> = row.try_get(db_column_name.as_str())?;
+        match value {
+            Some(value) => Ok(Some(parse_json(column_name.db_column_name, &value)?)),
+            None => Ok(None),
+        }
     }
 }
 
 impl<'s, TKey: DeserializeOwned + Eq + Hash + Ord, TValue: DeserializeOwned>
     FromDbRow<'s, BTreeMap<TKey, TValue>> for BTreeMap<TKey, TValue>
 {
-    fn from_db_row(
-        row: &crate::DbRow,
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> BTreeMap<TKey, TValue> {
+    ) -> Result<BTreeMap<TKey, TValue>, DecodeError> {
         let mut db_column_name = String::new();
         crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
 
-        let value: String = row.get(db_column_name.as_str());
-        serde_json::from_str(&value).unwrap()
+        let value: String = row.try_get(db_column_name.as_str())?;
+        parse_json(column_name.db_column_name, &value)
     }
 
-    fn from_db_row_opt(
-        row: &crate::DbRow,
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<BTreeMap<TKey, TValue>> {
+    ) -> Result<Option<BTreeMap<TKey, TValue>>, DecodeError> {
         let mut db_column_name = String::new();
         crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
 
-        let value: Option<String> = row.get(db_column_name.as_str());
-        let value = value.as_ref()?;
-        let result = serde_json::from_str(value).unwrap();
-        Some(result)
+        let value: Option<String> = row.try_get(db_column_name.as_str())?;
+        match value {
+            Some(value) => Ok(Some(parse_json(column_name.db_column_name, &value)?)),
+            None => Ok(None),
+        }
     }
 }
 
 impl<'s> FromDbRow<'s, f64> for f64 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> f64 {
-        row.get(column_name.db_column_name)
+    ) -> Result<f64, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<f64> {
-        row.get(column_name.db_column_name)
+    ) -> Result<Option<f64>, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 }
 
 impl<'s> FromDbRow<'s, f32> for f32 {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> f32 {
-        row.get(column_name.db_column_name)
+    ) -> Result<f32, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 
-    fn from_db_row_opt(
+    fn try_from_db_row_opt(
         row: &crate::DbRow,
         column_name: DbColumnName,
         _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<f32> {
-        row.get(column_name.db_column_name)
+    ) -> Result<Option<f32>, DecodeError> {
+        row.try_get(column_name.db_column_name)
     }
 }
 
 impl<'s> FromDbRow<'s, DateTimeAsMicroseconds> for DateTimeAsMicroseconds {
-    fn from_db_row(
+    fn try_from_db_row(
         row: &crate::DbRow,
         column_name: DbColumnName,
         metadata: &Option<SqlValueMetadata>,
-    ) -> DateTimeAsMicroseconds {
+    ) -> Result<DateTimeAsMicroseconds, DecodeError> {
+        match Self::try_from_db_row_opt(row, column_name, metadata)? {
+            Some(result) => Ok(result),
+            None => Err(DecodeError::null_value(
+                column_name.db_column_name,
+                "DateTimeAsMicroseconds",
+            )),
+        }
+    }
+
+    fn try_from_db_row_opt(
+        row: &crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<DateTimeAsMicroseconds>, DecodeError> {
         let mut db_column_name = String::new();
         crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
 
-        if let Some(metadata) = metadata {
-            if let Some(sql_type) = metadata.sql_type {
-                if sql_type == "timestamp" {
-                    let value: String = row.get(db_column_name.as_str());
-                    let result = DateTimeAsMicroseconds::from_str(value.as_str());
+        let value: Value = row.try_get(db_column_name.as_str())?;
 
-                    if result.is_none() {
-                        panic!("Field: {}. Can not convert timestamp value '{}' into DateTimeAsMicrosecond", db_column_name, value);
-                    }
-
-                    return result.unwrap();
-                }
-            }
+        match DateTimeStorage::from_metadata(metadata).try_decode(value) {
+            Ok(result) => Ok(result.map(DateTimeAsMicroseconds::new)),
+            Err(err) => Err(DecodeError::invalid_value(column_name.db_column_name, err)),
         }
+    }
+}
 
-        let unix_microseconds: i64 = row.get(db_column_name.as_str());
-        DateTimeAsMicroseconds::new(unix_microseconds)
+impl<'s, T: FromDbRow<'s, T>> FromDbRow<'s, Option<T>> for Option<T> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<T>, DecodeError> {
+        T::try_from_db_row_opt(row, column_name, metadata)
     }
 
-    fn from_db_row_opt(
-        row: &crate::DbRow,
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
         column_name: DbColumnName,
-        _metadata: &Option<SqlValueMetadata>,
-    ) -> Option<DateTimeAsMicroseconds> {
-        let mut db_column_name = String::new();
-        crate::utils::fill_adjusted_column_name(column_name.db_column_name, &mut db_column_name);
-        let unix_microseconds: Option<i64> = row.get(db_column_name.as_str());
-        let unix_microseconds = unix_microseconds?;
-        Some(DateTimeAsMicroseconds::new(unix_microseconds))
+        metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<Option<T>>, DecodeError> {
+        Ok(Some(T::try_from_db_row_opt(row, column_name, metadata)?))
     }
 }
@@ -1,4 +1,4 @@
-use crate::{sql::SelectBuilder, sql_where::SqlWhereModel, DbRow};
+use crate::{sql::SelectBuilder, sql_where::SqlWhereModel, DbRow, DecodeError};
 
 use super::BulkSelectBuilder;
 
@@ -8,7 +8,19 @@ pub trait BulkSelectEntity {
 
 pub trait SelectEntity {
     const SELECT_FIELDS: &'static [&'static str];
-    fn from(row: &DbRow) -> Self;
+    fn try_from_db_row(row: &DbRow) -> Result<Self, DecodeError>
+    where
+        Self: Sized;
+
+    fn from(row: &DbRow) -> Self
+    where
+        Self: Sized,
+    {
+        match Self::try_from_db_row(row) {
+            Ok(result) => result,
+            Err(err) => panic!("{}", err),
+        }
+    }
     fn fill_select_fields(select_builder: &mut SelectBuilder);
 
     //fn get_select_fields() -> &'static [&'static str];
@@ -27,3 +39,7 @@ pub trait SelectEntity {
         SelectBuilder::from_select_model::<TSelectEntity>()
     }
 }
+
+/// Implemented by `SelectDbEntity` for entities with non-nullable `order_by` fields
+/// and no `group_by` fields, which `query_page_by_cursor` can page through.
+pub trait CursorPageEntity: SelectEntity {}
@@ -4,7 +4,7 @@ use rust_extensions::date_time::DateTimeAsMicroseconds;
 
 use crate::{
     sql::{SelectBuilder, SelectFieldValue},
-    SqlValueMetadata,
+    DateTimeStorage, SqlValueMetadata,
 };
 
 use super::DbColumnName;
@@ -47,6 +47,16 @@ impl SelectValueProvider for usize {
     }
 }
 
+impl SelectValueProvider for isize {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(SelectFieldValue::Field(field_name));
+    }
+}
+
 impl SelectValueProvider for i64 {
     fn fill_select_part(
         sql: &mut SelectBuilder,
@@ -193,23 +203,16 @@ impl SelectValueProvider for DateTimeAsMicroseconds {
         field_name: DbColumnName,
         metadata: &Option<SqlValueMetadata>,
     ) {
-        if let Some(metadata) = metadata {
-            if let Some(sql_type) = metadata.sql_type {
-                if sql_type == "timestamp" {
-                    sql.push(SelectFieldValue::DateTimeAsTimestamp(field_name));
-                    return;
-                }
-
-                if sql_type == "bigint" {
-                    sql.push(SelectFieldValue::DateTimeAsBigint(field_name));
-
-                    return;
-                }
-
-                panic!("Unknown sql_type: {}", sql_type);
-            }
-        }
+        DateTimeStorage::from_metadata(metadata).fill_select_part(sql, field_name);
+    }
+}
 
-        panic!("sql_type is required for DateTimeAsMicroseconds");
+impl<T: SelectValueProvider> SelectValueProvider for Option<T> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        T::fill_select_part(sql, field_name, metadata);
     }
 }
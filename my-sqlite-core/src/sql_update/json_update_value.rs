@@ -0,0 +1,195 @@
+use serde::Serialize;
+
+use crate::{
+    sql::{SqlUpdateValue, SqlValues},
+    SqlValueMetadata,
+};
+
+use super::SqlUpdateValueProvider;
+
+pub struct JsonSet<T: SqlUpdateValueProvider> {
+    pub path: String,
+    pub value: T,
+}
+
+impl<T: SqlUpdateValueProvider> JsonSet<T> {
+    pub fn new(path: impl Into<String>, value: T) -> Self {
+        Self {
+            path: path.into(),
+            value,
+        }
+    }
+}
+
+impl<T: SqlUpdateValueProvider> SqlUpdateValueProvider for JsonSet<T> {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        let path = to_json_path(self.path.as_str());
+        let empty_document = get_empty_document(path.as_str());
+        let path_index = params.push(path.into());
+        let value = self.value.get_update_value(params, metadata);
+        SqlUpdateValue::JsonSet {
+            path_index,
+            empty_document,
+            value: Box::new(value),
+        }
+    }
+}
+
+pub struct JsonRemove(pub String);
+
+impl JsonRemove {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self(path.into())
+    }
+}
+
+impl SqlUpdateValueProvider for JsonRemove {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        let path = to_json_path(self.0.as_str());
+        let empty_document = get_empty_document(path.as_str());
+        let path_index = params.push(path.into());
+        SqlUpdateValue::JsonRemove {
+            path_index,
+            empty_document,
+        }
+    }
+}
+
+pub struct JsonPatch<T: Serialize>(pub T);
+
+impl<T: Serialize> SqlUpdateValueProvider for JsonPatch<T> {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        let index = params.push_json(&self.0);
+        SqlUpdateValue::JsonPatch(index)
+    }
+}
+
+fn to_json_path(path: &str) -> String {
+    if path.starts_with('$') {
+        return path.to_string();
+    }
+
+    let mut result = String::with_capacity(path.len() + 2);
+    result.push_str("$.");
+    result.push_str(path);
+    result
+}
+
+// A NULL column is replaced with an empty document of the kind the path addresses,
+// so '$[0]' works on an array column and '$.key' on an object column.
+fn get_empty_document(path: &str) -> &'static str {
+    if path.starts_with("$[") {
+        "[]"
+    } else {
+        "{}"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use async_sqlite::rusqlite::ToSql;
+
+    use crate::{
+        sql::SqlValues,
+        sql_update::{SqlUpdateModel, SqlUpdateModelValue, SqlUpdateValueProvider},
+        ColumnName,
+    };
+
+    use super::*;
+
+    struct UpdateModel {
+        set: JsonSet<String>,
+        remove: JsonRemove,
+        patch: JsonPatch<BTreeMap<String, String>>,
+    }
+
+    impl SqlUpdateModel for UpdateModel {
+        fn get_column_name(no: usize) -> ColumnName {
+            match no {
+                0 => "settings".into(),
+                1 => "tags".into(),
+                _ => "context".into(),
+            }
+        }
+
+        fn get_field_value(&self, no: usize) -> SqlUpdateModelValue {
+            let value: &dyn SqlUpdateValueProvider = match no {
+                0 => &self.set,
+                1 => &self.remove,
+                _ => &self.patch,
+            };
+
+            SqlUpdateModelValue {
+                metadata: None,
+                ignore_if_none: false,
+                value: Some(value.into()),
+            }
+        }
+
+        fn get_fields_amount() -> usize {
+            3
+        }
+    }
+
+    #[test]
+    fn test_json_update_sql() {
+        let mut patch = BTreeMap::new();
+        patch.insert("key".to_string(), "value".to_string());
+
+        let model = UpdateModel {
+            set: JsonSet::new("theme", "dark".to_string()),
+            remove: JsonRemove::new("$.legacy"),
+            patch: JsonPatch(patch),
+        };
+
+        let mut sql = String::new();
+        let mut params = SqlValues::new();
+
+        model.build_update_sql_part(&mut sql, &mut params);
+
+        assert_eq!(
+            "(settings,tags,context)=(json_set(coalesce(settings,'{}'),$1,$2),json_remove(coalesce(tags,'{}'),$3),json_patch(coalesce(context,'{}'),$4))",
+            sql
+        );
+
+        assert_eq!("$.theme", params.get(0).unwrap().as_str().unwrap());
+        assert_eq!("$.legacy", params.get(2).unwrap().as_str().unwrap());
+    }
+
+    #[test]
+    fn test_patch_serialization_error_fails_the_bind() {
+        let mut patch = BTreeMap::new();
+        patch.insert((1, 2), "value".to_string());
+
+        let mut params = SqlValues::new();
+        JsonPatch(patch).get_update_value(&mut params, &None);
+
+        assert!(params.get(0).unwrap().to_sql().to_sql().is_err());
+    }
+
+    #[test]
+    fn test_array_path_defaults_to_empty_array() {
+        let mut params = SqlValues::new();
+
+        let value = JsonSet::new("$[#]", "tag".to_string()).get_update_value(&mut params, &None);
+
+        let mut sql = String::new();
+        value.write_for_column(&"tags".into(), &mut sql);
+
+        assert_eq!("json_set(coalesce(tags,'[]'),$1,$2)", sql);
+    }
+}
@@ -1,6 +1,8 @@
+mod json_update_value;
 mod model_update_value;
 mod sql_update_model;
 mod sql_update_value_provider;
+pub use json_update_value::*;
 pub use model_update_value::*;
 pub use sql_update_model::*;
 pub use sql_update_value_provider::*;
@@ -1,11 +1,43 @@
-use crate::{sql::SqlValues, SqlValueMetadata};
+use crate::{
+    sql::{SqlUpdateValue, SqlValues},
+    ColumnName, JsonValueProvider, SqlValueMetadata,
+};
 
 use super::SqlUpdateValueProvider;
 
+/// Value of an update field. Fields with `#[db_codec]` encode into an owned value,
+/// so `SqlUpdateModelValue::value` holds this instead of `&dyn SqlUpdateValueProvider`.
+/// Code building `SqlUpdateModelValue` by hand wraps the reference with `.into()`.
+pub enum SqlUpdateValueHolder<'s> {
+    Borrowed(&'s dyn SqlUpdateValueProvider),
+    Owned(Box<dyn SqlUpdateValueProvider + 's>),
+    Json(&'s dyn JsonValueProvider),
+}
+
+impl<'s> SqlUpdateValueHolder<'s> {
+    pub fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        match self {
+            Self::Borrowed(value) => value.get_update_value(params, metadata),
+            Self::Owned(value) => value.get_update_value(params, metadata),
+            Self::Json(value) => SqlUpdateValue::Json(value.push_json_param(params)),
+        }
+    }
+}
+
+impl<'s> From<&'s dyn SqlUpdateValueProvider> for SqlUpdateValueHolder<'s> {
+    fn from(value: &'s dyn SqlUpdateValueProvider) -> Self {
+        Self::Borrowed(value)
+    }
+}
+
 pub struct SqlUpdateModelValue<'s> {
     pub metadata: Option<SqlValueMetadata>,
     pub ignore_if_none: bool,
-    pub value: Option<&'s dyn SqlUpdateValueProvider>,
+    pub value: Option<SqlUpdateValueHolder<'s>>,
 }
 
 impl<'s> SqlUpdateModelValue<'s> {
@@ -20,4 +52,21 @@ impl<'s> SqlUpdateModelValue<'s> {
             }
         }
     }
+
+    pub fn write_update_value(
+        &self,
+        column_name: &ColumnName,
+        sql: &mut String,
+        params: &mut SqlValues,
+    ) {
+        match &self.value {
+            Some(value) => {
+                let value = value.get_update_value(params, &self.metadata);
+                value.write_for_column(column_name, sql)
+            }
+            None => {
+                sql.push_str("NULL");
+            }
+        }
+    }
 }
@@ -51,8 +51,9 @@ pub trait SqlUpdateModel {
                 sql.push(',');
             }
 
+            let column_name = Self::get_column_name(i);
             let update_data = self.get_field_value(i);
-            update_data.write_value(sql, params);
+            update_data.write_update_value(&column_name, sql, params);
         }
 
         if need_parentheses {
@@ -5,7 +5,7 @@ use serde::Serialize;
 
 use crate::{
     sql::{SqlUpdateValue, SqlValues},
-    SqlValueMetadata,
+    DateTimeStorage, SqlValueMetadata,
 };
 
 pub trait SqlUpdateValueProvider {
@@ -44,24 +44,7 @@ impl SqlUpdateValueProvider for DateTimeAsMicroseconds {
         _: &mut SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        if let Some(metadata) = &metadata {
-            if let Some(sql_type) = metadata.sql_type {
-                if sql_type == "bigint" {
-                    return SqlUpdateValue::NonStringValue(
-                        self.unix_microseconds.to_string().into(),
-                    );
-                }
-
-                if sql_type == "timestamp" {
-                    let value = &self.to_rfc3339()[..26];
-                    return SqlUpdateValue::StringValue(value.to_string().into());
-                }
-
-                panic!("Unknown sql type: {}", sql_type);
-            }
-        }
-
-        panic!("DateTimeAsMicroseconds requires sql_type");
+        DateTimeStorage::from_metadata(metadata).encode(self.unix_microseconds)
     }
 }
 
@@ -81,100 +64,126 @@ impl SqlUpdateValueProvider for bool {
 impl SqlUpdateValueProvider for u8 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
 impl SqlUpdateValueProvider for i8 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
 impl SqlUpdateValueProvider for u16 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
 impl SqlUpdateValueProvider for f32 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_f64(*self as f64))
     }
 }
 
 impl SqlUpdateValueProvider for f64 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_f64(*self))
     }
 }
 
 impl SqlUpdateValueProvider for i16 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
 impl SqlUpdateValueProvider for u32 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
 impl SqlUpdateValueProvider for i32 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
 impl SqlUpdateValueProvider for u64 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        match i64::try_from(*self) {
+            Ok(value) => SqlUpdateValue::Index(params.push_i64(value)),
+            Err(_) => SqlUpdateValue::NonStringValue(self.to_string()),
+        }
     }
 }
 
 impl SqlUpdateValueProvider for i64 {
     fn get_update_value(
         &self,
-        _: &mut SqlValues,
+        params: &mut SqlValues,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
+    }
+}
+
+impl SqlUpdateValueProvider for usize {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
         _metadata: &Option<SqlValueMetadata>,
     ) -> SqlUpdateValue {
-        SqlUpdateValue::NonStringValue(self.to_string().into())
+        match i64::try_from(*self) {
+            Ok(value) => SqlUpdateValue::Index(params.push_i64(value)),
+            Err(_) => SqlUpdateValue::NonStringValue(self.to_string()),
+        }
+    }
+}
+
+impl SqlUpdateValueProvider for isize {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        SqlUpdateValue::Index(params.push_i64(*self as i64))
     }
 }
 
@@ -215,3 +224,16 @@ impl<TKey: Serialize, TVale: Serialize> SqlUpdateValueProvider for BTreeMap<TKey
         SqlUpdateValue::Json(index)
     }
 }
+
+impl<T: SqlUpdateValueProvider> SqlUpdateValueProvider for Option<T> {
+    fn get_update_value(
+        &self,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> SqlUpdateValue {
+        match self {
+            Some(value) => value.get_update_value(params, metadata),
+            None => SqlUpdateValue::NonStringValue("NULL".into()),
+        }
+    }
+}
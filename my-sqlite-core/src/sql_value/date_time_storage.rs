@@ -0,0 +1,382 @@
+use async_sqlite::rusqlite::types::Value;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{
+    sql::{SelectBuilder, SelectFieldValue, SqlUpdateValue},
+    sql_select::DbColumnName,
+    table_schema::TableColumnType,
+    RenderFullWhereCondition,
+};
+
+use super::SqlValueMetadata;
+
+const MICROSECONDS_IN_DAY: i64 = 86_400_000_000;
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2440587.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeStorage {
+    UnixMicroseconds,
+    UnixMilliseconds,
+    UnixSeconds,
+    IsoText { precision: usize },
+    JulianDay,
+}
+
+impl DateTimeStorage {
+    pub fn from_sql_type(sql_type: &str) -> Option<Self> {
+        match sql_type {
+            "bigint" | "unix_microseconds" => Some(Self::UnixMicroseconds),
+            "unix_milliseconds" => Some(Self::UnixMilliseconds),
+            "unix_seconds" => Some(Self::UnixSeconds),
+            "timestamp" => Some(Self::IsoText { precision: 6 }),
+            "timestamp_milliseconds" => Some(Self::IsoText { precision: 3 }),
+            "timestamp_seconds" => Some(Self::IsoText { precision: 0 }),
+            "julianday" => Some(Self::JulianDay),
+            _ => None,
+        }
+    }
+
+    pub fn from_metadata(metadata: &Option<SqlValueMetadata>) -> Self {
+        if let Some(metadata) = metadata {
+            if let Some(sql_type) = metadata.sql_type {
+                return match Self::from_sql_type(sql_type) {
+                    Some(result) => result,
+                    None => panic!("Unknown sql_type for DateTimeAsMicroseconds: {}", sql_type),
+                };
+            }
+        }
+
+        panic!("DateTimeAsMicroseconds requires sql_type");
+    }
+
+    pub fn from_metadata_or_default(metadata: &Option<SqlValueMetadata>, default: Self) -> Self {
+        if let Some(metadata) = metadata {
+            if metadata.sql_type.is_some() {
+                return Self::from_metadata(&Some(SqlValueMetadata {
+                    sql_type: metadata.sql_type,
+                    ..Default::default()
+                }));
+            }
+        }
+
+        default
+    }
+
+    pub fn get_table_column_type(&self) -> TableColumnType {
+        match self {
+            Self::UnixMicroseconds | Self::UnixMilliseconds | Self::UnixSeconds => {
+                TableColumnType::BigInt
+            }
+            Self::IsoText { .. } => TableColumnType::Timestamp,
+            Self::JulianDay => TableColumnType::Double,
+        }
+    }
+
+    pub fn encode(&self, unix_microseconds: i64) -> SqlUpdateValue {
+        match self {
+            Self::UnixMicroseconds => SqlUpdateValue::NonStringValue(unix_microseconds.to_string()),
+            Self::UnixMilliseconds => SqlUpdateValue::NonStringValue(
+                unix_microseconds.div_euclid(1_000).to_string(),
+            ),
+            Self::UnixSeconds => SqlUpdateValue::NonStringValue(
+                unix_microseconds.div_euclid(1_000_000).to_string(),
+            ),
+            Self::IsoText { precision } => {
+                SqlUpdateValue::StringValue(format_iso_text(unix_microseconds, *precision))
+            }
+            Self::JulianDay => {
+                let julian_day = unix_microseconds as f64 / MICROSECONDS_IN_DAY as f64
+                    + UNIX_EPOCH_JULIAN_DAY;
+                SqlUpdateValue::NonStringValue(format!("{:?}", julian_day))
+            }
+        }
+    }
+
+    pub fn try_decode(&self, value: Value) -> Result<Option<i64>, String> {
+        match value {
+            Value::Null => Ok(None),
+            Value::Integer(value) => match self {
+                Self::UnixMilliseconds => scale_integer(value, 1_000).map(Some),
+                Self::UnixSeconds => scale_integer(value, 1_000_000).map(Some),
+                Self::JulianDay => Ok(Some(julian_day_to_unix_microseconds(value as f64))),
+                _ => Ok(Some(value)),
+            },
+            Value::Real(value) => match self {
+                Self::UnixMilliseconds => Ok(Some((value * 1_000.0).round() as i64)),
+                Self::UnixSeconds => Ok(Some((value * 1_000_000.0).round() as i64)),
+                Self::JulianDay => Ok(Some(julian_day_to_unix_microseconds(value))),
+                _ => Ok(Some(value.round() as i64)),
+            },
+            Value::Text(value) => match parse_iso_text(value.as_str()) {
+                Some(result) => Ok(Some(result)),
+                None => match DateTimeAsMicroseconds::from_str(value.as_str()) {
+                    Some(result) => Ok(Some(result.unix_microseconds)),
+                    None => Err(format!(
+                        "Can not convert timestamp value '{}' into DateTimeAsMicroseconds",
+                        value
+                    )),
+                },
+            },
+            Value::Blob(_) => {
+                Err("Blob value can not be converted into DateTimeAsMicroseconds".to_string())
+            }
+        }
+    }
+
+    pub fn fill_where_value(
+        &self,
+        unix_microseconds: i64,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        let value = self.encode(unix_microseconds);
+
+        if let Some(full_where_condition) = full_where_condition {
+            full_where_condition.render_param_name(sql, "=", metadata);
+        }
+
+        value.write(sql);
+
+        true
+    }
+
+    pub fn fill_select_part(&self, sql: &mut SelectBuilder, field_name: DbColumnName) {
+        match self {
+            Self::IsoText { .. } => sql.push(SelectFieldValue::DateTimeAsTimestamp(field_name)),
+            _ => sql.push(SelectFieldValue::DateTimeAsBigint(field_name)),
+        }
+    }
+}
+
+fn scale_integer(value: i64, multiplier: i64) -> Result<i64, String> {
+    match value.checked_mul(multiplier) {
+        Some(result) => Ok(result),
+        None => Err(format!(
+            "Timestamp value {} is out of range for DateTimeAsMicroseconds",
+            value
+        )),
+    }
+}
+
+fn julian_day_to_unix_microseconds(julian_day: f64) -> i64 {
+    ((julian_day - UNIX_EPOCH_JULIAN_DAY) * MICROSECONDS_IN_DAY as f64).round() as i64
+}
+
+pub fn format_iso_text(unix_microseconds: i64, precision: usize) -> String {
+    let days = unix_microseconds.div_euclid(MICROSECONDS_IN_DAY);
+    let micros_of_day = unix_microseconds.rem_euclid(MICROSECONDS_IN_DAY);
+
+    let (year, month, day) = civil_from_days(days);
+
+    let seconds_of_day = micros_of_day / 1_000_000;
+    let fraction = micros_of_day % 1_000_000;
+
+    let mut result = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        (seconds_of_day / 60) % 60,
+        seconds_of_day % 60
+    );
+
+    if precision > 0 {
+        let fraction = format!("{:06}", fraction);
+        result.push('.');
+        result.push_str(&fraction[..precision.min(6)]);
+    }
+
+    result
+}
+
+pub fn parse_iso_text(src: &str) -> Option<i64> {
+    let src = src.trim();
+
+    if !src.is_ascii() {
+        return None;
+    }
+
+    let bytes = src.as_bytes();
+
+    if bytes.len() < 19 {
+        return None;
+    }
+
+    if bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+
+    if bytes[10] != b'T' && bytes[10] != b' ' {
+        return None;
+    }
+
+    let year: i64 = src[0..4].parse().ok()?;
+    let month: i64 = src[5..7].parse().ok()?;
+    let day: i64 = src[8..10].parse().ok()?;
+    let hour: i64 = src[11..13].parse().ok()?;
+    let minute: i64 = src[14..16].parse().ok()?;
+    let second: i64 = src[17..19].parse().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let mut rest = &src[19..];
+    let mut fraction: i64 = 0;
+
+    if rest.starts_with('.') {
+        let digits_len = rest[1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - 1);
+
+        let digits = &rest[1..1 + digits_len];
+        for (no, c) in digits.chars().take(6).enumerate() {
+            fraction += (c as i64 - '0' as i64) * 10_i64.pow(5 - no as u32);
+        }
+
+        rest = &rest[1 + digits_len..];
+    }
+
+    let offset_seconds = match rest {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+
+            let offset = rest[1..].replace(':', "");
+            if offset.len() != 4 {
+                return None;
+            }
+
+            let hours: i64 = offset[0..2].parse().ok()?;
+            let minutes: i64 = offset[2..4].parse().ok()?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86_400 + hour * 3600 + minute * 60 + second - offset_seconds;
+
+    Some(seconds * 1_000_000 + fraction)
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use async_sqlite::rusqlite::types::Value;
+
+    use super::*;
+
+    const MOMENT: i64 = 1_609_556_645_123_456;
+
+    #[test]
+    fn test_iso_text_precision() {
+        assert_eq!("2021-01-02T03:04:05.123456", format_iso_text(MOMENT, 6));
+        assert_eq!("2021-01-02T03:04:05.123", format_iso_text(MOMENT, 3));
+        assert_eq!("2021-01-02T03:04:05", format_iso_text(MOMENT, 0));
+    }
+
+    #[test]
+    fn test_parse_iso_text() {
+        assert_eq!(Some(MOMENT), parse_iso_text("2021-01-02T03:04:05.123456"));
+        assert_eq!(Some(MOMENT), parse_iso_text("2021-01-02 03:04:05.123456Z"));
+        assert_eq!(
+            Some(MOMENT),
+            parse_iso_text("2021-01-02T05:04:05.123456+02:00")
+        );
+        assert_eq!(
+            Some(MOMENT - 123_456),
+            parse_iso_text("2021-01-02T03:04:05")
+        );
+        assert_eq!(None, parse_iso_text("2021-01-02T03:04:0é"));
+        assert_eq!(None, parse_iso_text("2021-01-02T03:04:05.12é"));
+        assert_eq!(None, parse_iso_text("2021-13-02T03:04:05"));
+        assert_eq!(None, parse_iso_text("2021-01-32T03:04:05"));
+        assert_eq!(None, parse_iso_text("2021-01-02T24:04:05"));
+        assert_eq!(None, parse_iso_text("2021-01-02T03:60:05"));
+        assert_eq!(None, parse_iso_text("2021-01-02T03:04:05+24:00"));
+    }
+
+    #[test]
+    fn test_integer_overflow_is_an_error() {
+        assert!(DateTimeStorage::UnixSeconds
+            .try_decode(Value::Integer(i64::MAX))
+            .is_err());
+        assert!(DateTimeStorage::UnixMilliseconds
+            .try_decode(Value::Integer(i64::MIN))
+            .is_err());
+    }
+
+    #[test]
+    fn test_round_trip_for_each_storage() {
+        let storages = [
+            (DateTimeStorage::UnixMicroseconds, MOMENT),
+            (DateTimeStorage::UnixMilliseconds, MOMENT - 456),
+            (DateTimeStorage::UnixSeconds, MOMENT - 123_456),
+            (DateTimeStorage::IsoText { precision: 6 }, MOMENT),
+            (DateTimeStorage::IsoText { precision: 3 }, MOMENT - 456),
+            (DateTimeStorage::IsoText { precision: 0 }, MOMENT - 123_456),
+        ];
+
+        for (storage, expected) in storages {
+            let value = match storage.encode(MOMENT) {
+                SqlUpdateValue::NonStringValue(value) => Value::Integer(value.parse().unwrap()),
+                SqlUpdateValue::StringValue(value) => Value::Text(value),
+                _ => panic!("Unexpected encoded value"),
+            };
+
+            assert_eq!(Some(expected), storage.try_decode(value).unwrap(), "{:?}", storage);
+        }
+    }
+
+    #[test]
+    fn test_julian_day() {
+        let value = match DateTimeStorage::JulianDay.encode(MOMENT) {
+            SqlUpdateValue::NonStringValue(value) => value.parse::<f64>().unwrap(),
+            _ => panic!("Unexpected encoded value"),
+        };
+
+        let decoded = DateTimeStorage::JulianDay
+            .try_decode(Value::Real(value))
+            .unwrap()
+            .unwrap();
+
+        assert!((decoded - MOMENT).abs() < 1_000);
+    }
+}
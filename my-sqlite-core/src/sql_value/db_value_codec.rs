@@ -0,0 +1,52 @@
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    table_schema::{SqlTypeProvider, TableColumnType},
+    DbRow, DecodeError, SqlValueMetadata,
+};
+
+pub fn codec_get_sql_type<T, TDb: SqlTypeProvider>(
+    _encode: fn(&T) -> TDb,
+    metadata: Option<SqlValueMetadata>,
+) -> TableColumnType {
+    TDb::get_sql_type(metadata)
+}
+
+pub fn codec_fill_select_part<T, TDb: SelectValueProvider>(
+    _encode: fn(&T) -> TDb,
+    sql: &mut SelectBuilder,
+    field_name: DbColumnName,
+    metadata: &Option<SqlValueMetadata>,
+) {
+    TDb::fill_select_part(sql, field_name, metadata);
+}
+
+pub fn codec_from_db_row<'s, T, TDb: FromDbRow<'s, TDb>>(
+    decode: fn(TDb) -> Result<T, String>,
+    row: &'s DbRow,
+    column_name: DbColumnName,
+    metadata: &Option<SqlValueMetadata>,
+) -> Result<T, DecodeError> {
+    let value = TDb::try_from_db_row(row, column_name, metadata)?;
+
+    match decode(value) {
+        Ok(result) => Ok(result),
+        Err(err) => Err(DecodeError::invalid_value(column_name.db_column_name, err)),
+    }
+}
+
+pub fn codec_from_db_row_opt<'s, T, TDb: FromDbRow<'s, TDb>>(
+    decode: fn(TDb) -> Result<T, String>,
+    row: &'s DbRow,
+    column_name: DbColumnName,
+    metadata: &Option<SqlValueMetadata>,
+) -> Result<Option<T>, DecodeError> {
+    let Some(value) = TDb::try_from_db_row_opt(row, column_name, metadata)? else {
+        return Ok(None);
+    };
+
+    match decode(value) {
+        Ok(result) => Ok(Some(result)),
+        Err(err) => Err(DecodeError::invalid_value(column_name.db_column_name, err)),
+    }
+}
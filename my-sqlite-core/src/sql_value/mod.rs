@@ -1,3 +1,7 @@
+mod date_time_storage;
+mod db_value_codec;
 mod sql_value_metadata;
 
+pub use date_time_storage::*;
+pub use db_value_codec::*;
 pub use sql_value_metadata::*;
@@ -1,4 +1,42 @@
+#[derive(Default)]
+#[non_exhaustive]
 pub struct SqlValueMetadata {
     pub sql_type: Option<&'static str>,
     pub operator: Option<&'static str>,
+    pub separator: Option<&'static str>,
+    pub json_object_key: Option<&'static str>,
+    pub time_bucket: Option<&'static str>,
+    pub window: Option<&'static str>,
+}
+
+impl SqlValueMetadata {
+    pub fn with_sql_type(mut self, sql_type: &'static str) -> Self {
+        self.sql_type = Some(sql_type);
+        self
+    }
+
+    pub fn with_operator(mut self, operator: &'static str) -> Self {
+        self.operator = Some(operator);
+        self
+    }
+
+    pub fn with_separator(mut self, separator: &'static str) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    pub fn with_json_object_key(mut self, json_object_key: &'static str) -> Self {
+        self.json_object_key = Some(json_object_key);
+        self
+    }
+
+    pub fn with_time_bucket(mut self, time_bucket: &'static str) -> Self {
+        self.time_bucket = Some(time_bucket);
+        self
+    }
+
+    pub fn with_window(mut self, window: &'static str) -> Self {
+        self.window = Some(window);
+        self
+    }
 }
@@ -0,0 +1,141 @@
+use crate::{sql::SqlValues, SqlValueMetadata};
+
+use super::{RenderFullWhereCondition, SqlWhereValueProvider};
+
+pub const JSON_CONTAINS_OPERATOR: &str = "json_contains";
+
+pub struct JsonArrayContains<T: SqlWhereValueProvider>(pub T);
+
+impl<T: SqlWhereValueProvider> SqlWhereValueProvider for JsonArrayContains<T> {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        fill_json_array_contains(&self.0, full_where_condition, sql, params, metadata)
+    }
+
+    fn render_value(&self) -> bool {
+        self.0.render_value()
+    }
+}
+
+pub fn fill_json_array_contains(
+    value: &dyn SqlWhereValueProvider,
+    full_where_condition: Option<RenderFullWhereCondition>,
+    sql: &mut String,
+    params: &mut SqlValues,
+    metadata: &Option<SqlValueMetadata>,
+) -> bool {
+    let full_where_condition = match full_where_condition {
+        Some(full_where_condition) => full_where_condition,
+        None => panic!("json_contains condition requires column name"),
+    };
+
+    if full_where_condition.condition_no > 0 {
+        sql.push_str(" AND ");
+    }
+
+    // An empty set of values can not be contained by any row.
+    if !value.render_value() {
+        sql.push('0');
+        return true;
+    }
+
+    sql.push_str("EXISTS (SELECT 1 FROM json_each(");
+    sql.push_str(full_where_condition.column_name);
+    sql.push_str(") WHERE ");
+
+    let value_metadata = metadata.as_ref().map(|metadata| SqlValueMetadata {
+        sql_type: metadata.sql_type,
+        ..Default::default()
+    });
+
+    value.fill_where_value(
+        Some(RenderFullWhereCondition {
+            condition_no: 0,
+            column_name: "value",
+            json_prefix: None,
+        }),
+        sql,
+        params,
+        &value_metadata,
+    );
+
+    sql.push(')');
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{sql::SqlValues, RenderFullWhereCondition, SqlWhereValueProvider};
+
+    use super::JsonArrayContains;
+
+    #[test]
+    fn test_contains_single_value() {
+        let mut sql = String::new();
+        let mut params = SqlValues::new();
+
+        JsonArrayContains("rust".to_string()).fill_where_value(
+            Some(RenderFullWhereCondition {
+                condition_no: 1,
+                column_name: "tags",
+                json_prefix: None,
+            }),
+            &mut sql,
+            &mut params,
+            &None,
+        );
+
+        assert_eq!(
+            " AND EXISTS (SELECT 1 FROM json_each(tags) WHERE value=$1)",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_contains_any_of() {
+        let mut sql = String::new();
+        let mut params = SqlValues::new();
+
+        JsonArrayContains(vec!["rust".to_string(), "sqlite".to_string()]).fill_where_value(
+            Some(RenderFullWhereCondition {
+                condition_no: 0,
+                column_name: "tags",
+                json_prefix: None,
+            }),
+            &mut sql,
+            &mut params,
+            &None,
+        );
+
+        assert_eq!(
+            "EXISTS (SELECT 1 FROM json_each(tags) WHERE value IN ($1,$2))",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_contains_empty_set_matches_nothing() {
+        let mut sql = String::new();
+        let mut params = SqlValues::new();
+
+        let rendered = JsonArrayContains(Vec::<String>::new()).fill_where_value(
+            Some(RenderFullWhereCondition {
+                condition_no: 1,
+                column_name: "tags",
+                json_prefix: None,
+            }),
+            &mut sql,
+            &mut params,
+            &None,
+        );
+
+        assert!(rendered);
+        assert_eq!(" AND 0", sql);
+    }
+}
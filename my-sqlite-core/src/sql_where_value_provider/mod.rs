@@ -2,3 +2,5 @@ mod sql_where_value_provider;
 pub use sql_where_value_provider::*;
 mod render_full_where_condition;
 pub use render_full_where_condition::*;
+mod json_array_contains;
+pub use json_array_contains::*;
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::SqlValueMetadata;
+use crate::{DateTimeStorage, SqlValueMetadata};
 
 use super::RenderFullWhereCondition;
 
@@ -72,30 +72,12 @@ impl SqlWhereValueProvider for DateTimeAsMicroseconds {
         _params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
-        if let Some(full_where_condition) = full_where_condition {
-            full_where_condition.render_param_name(sql, "=", metadata);
-        }
-
-        if let Some(metadata) = &metadata {
-            if let Some(sql_type) = metadata.sql_type {
-                if sql_type == "bigint" {
-                    sql.push_str(self.unix_microseconds.to_string().as_str());
-
-                    return true;
-                }
-
-                if sql_type == "timestamp" {
-                    sql.push('\'');
-                    sql.push_str(&self.to_rfc3339()[..19]);
-                    sql.push('\'');
-                    return true;
-                }
-
-                panic!("Unknown sql type: {}", sql_type);
-            }
-        }
-
-        panic!("DateTimeAsMicroseconds requires sql_type");
+        DateTimeStorage::from_metadata(metadata).fill_where_value(
+            self.unix_microseconds,
+            full_where_condition,
+            sql,
+            metadata,
+        )
     }
 
     fn render_value(&self) -> bool {
@@ -133,13 +115,16 @@ impl SqlWhereValueProvider for u8 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -154,13 +139,16 @@ impl SqlWhereValueProvider for i8 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -175,13 +163,16 @@ impl SqlWhereValueProvider for u16 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -196,13 +187,16 @@ impl SqlWhereValueProvider for f32 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_f64(*self as f64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -217,13 +211,16 @@ impl SqlWhereValueProvider for f64 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_f64(*self);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -238,13 +235,16 @@ impl SqlWhereValueProvider for i16 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -259,13 +259,16 @@ impl SqlWhereValueProvider for u32 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -280,13 +283,16 @@ impl SqlWhereValueProvider for i32 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -301,13 +307,21 @@ impl SqlWhereValueProvider for u64 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        match i64::try_from(*self) {
+            Ok(value) => {
+                let index = params.push_i64(value);
+                sql.push('$');
+                sql.push_str(index.to_string().as_str());
+            }
+            Err(_) => sql.push_str(self.to_string().as_str()),
+        }
 
         true
     }
@@ -322,13 +336,69 @@ impl SqlWhereValueProvider for i64 {
         &self,
         full_where_condition: Option<RenderFullWhereCondition>,
         sql: &mut String,
-        _params: &mut crate::sql::SqlValues,
+        params: &mut crate::sql::SqlValues,
         metadata: &Option<SqlValueMetadata>,
     ) -> bool {
         if let Some(full_where_condition) = full_where_condition {
             full_where_condition.render_param_name(sql, "=", metadata);
         }
-        sql.push_str(self.to_string().as_str());
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
+
+        true
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
+
+impl SqlWhereValueProvider for usize {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut crate::sql::SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        if let Some(full_where_condition) = full_where_condition {
+            full_where_condition.render_param_name(sql, "=", metadata);
+        }
+
+        match i64::try_from(*self) {
+            Ok(value) => {
+                let index = params.push_i64(value);
+                sql.push('$');
+                sql.push_str(index.to_string().as_str());
+            }
+            Err(_) => sql.push_str(self.to_string().as_str()),
+        }
+
+        true
+    }
+
+    fn render_value(&self) -> bool {
+        true
+    }
+}
+
+impl SqlWhereValueProvider for isize {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut crate::sql::SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        if let Some(full_where_condition) = full_where_condition {
+            full_where_condition.render_param_name(sql, "=", metadata);
+        }
+
+        let index = params.push_i64(*self as i64);
+        sql.push('$');
+        sql.push_str(index.to_string().as_str());
 
         true
     }
@@ -456,3 +526,25 @@ impl SqlWhereValueProvider for BTreeMap<String, String> {
         self.len() > 0
     }
 }
+
+impl<T: SqlWhereValueProvider> SqlWhereValueProvider for Option<T> {
+    fn fill_where_value(
+        &self,
+        full_where_condition: Option<RenderFullWhereCondition>,
+        sql: &mut String,
+        params: &mut crate::sql::SqlValues,
+        metadata: &Option<SqlValueMetadata>,
+    ) -> bool {
+        match self {
+            Some(value) => value.fill_where_value(full_where_condition, sql, params, metadata),
+            None => crate::IsNull::Yes.fill_where_value(full_where_condition, sql, params, &None),
+        }
+    }
+
+    fn render_value(&self) -> bool {
+        match self {
+            Some(value) => value.render_value(),
+            None => true,
+        }
+    }
+}
@@ -1,44 +1,83 @@
-use std::sync::Arc;
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
 
-use async_sqlite::Client;
+use async_sqlite::{rusqlite::InterruptHandle, Client};
+use futures_core::Stream;
+#[cfg(feature = "with-logs-and-telemetry")]
+use my_telemetry::MyTelemetryContext;
+#[cfg(feature = "with-logs-and-telemetry")]
+use rust_extensions::date_time::DateTimeAsMicroseconds;
 use rust_extensions::StrOrString;
 
 use crate::{
     sql::{SelectBuilder, SqlValues},
     sql_select::SelectEntity,
     sql_where::SqlWhereModel,
-    DbRow, SqlLiteError,
+    DbRow, InterruptGuard, SqlExecutionState, SqlLiteError, SqlLiteLogger, SqlLogEvent,
+    SqlLogLevel, SqlMetrics, SqlMetricsRecord, SqlOperation, SqlWaitTime,
 };
+#[cfg(feature = "with-logs-and-telemetry")]
+use crate::{SqlRequest, SqlTelemetrySink};
+
+pub(crate) struct QueryStreamReporter {
+    pub logger: Arc<dyn SqlLiteLogger>,
+    pub log_level: SqlLogLevel,
+    pub metrics: Arc<SqlMetrics>,
+    pub redact_sql_in_errors: bool,
+    #[cfg(feature = "with-logs-and-telemetry")]
+    pub telemetry_sink: Arc<dyn SqlTelemetrySink>,
+    #[cfg(feature = "with-logs-and-telemetry")]
+    pub telemetry_context: Option<MyTelemetryContext>,
+}
 
 pub struct SqliteQueryStream<TEntity: SelectEntity + Send + Sync + 'static> {
-    rx: tokio::sync::mpsc::Receiver<Result<TEntity, async_sqlite::rusqlite::Error>>,
+    rx: tokio::sync::mpsc::Receiver<Result<TEntity, SqlLiteError>>,
+    _interrupt_guard: InterruptGuard,
 }
 
 impl<TEntity: SelectEntity + Send + Sync + 'static> SqliteQueryStream<TEntity> {
-    pub fn new<TWhereModel: SqlWhereModel + Send + Sync + 'static>(
+    pub(crate) fn new<TWhereModel: SqlWhereModel + Send + Sync + 'static>(
         client: Arc<Client>,
         table_name: StrOrString<'static>,
         select_builder: SelectBuilder,
         where_model: Option<TWhereModel>,
+        reporter: QueryStreamReporter,
+        interrupt_handle: Arc<InterruptHandle>,
+        channel_capacity: usize,
     ) -> Self {
-        let (tx, rx) = tokio::sync::mpsc::channel(2048);
+        let (tx, rx) = tokio::sync::mpsc::channel(channel_capacity.max(1));
+        let interrupt_guard = InterruptGuard::new(interrupt_handle);
+
         tokio::spawn(select_builder_stream::<TEntity, TWhereModel>(
             client,
             table_name,
             select_builder,
             where_model,
             tx,
+            reporter,
+            interrupt_guard.get_state(),
         ));
-        Self { rx }
+
+        Self {
+            rx,
+            _interrupt_guard: interrupt_guard,
+        }
     }
 
     pub async fn get_next(&mut self) -> Option<Result<TEntity, SqlLiteError>> {
-        let next_one = self.rx.recv().await?;
+        self.rx.recv().await
+    }
+}
 
-        match next_one {
-            Ok(item) => Some(Ok(item)),
-            Err(err) => Some(Err(err.into())),
-        }
+impl<TEntity: SelectEntity + Send + Sync + 'static> Stream for SqliteQueryStream<TEntity> {
+    type Item = Result<TEntity, SqlLiteError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().rx.poll_recv(cx)
     }
 }
 
@@ -50,7 +89,9 @@ async fn select_builder_stream<
     table_name: StrOrString<'static>,
     select_builder: SelectBuilder,
     where_model: Option<TWhereModel>,
-    tx: tokio::sync::mpsc::Sender<Result<TEntity, async_sqlite::rusqlite::Error>>,
+    tx: tokio::sync::mpsc::Sender<Result<TEntity, SqlLiteError>>,
+    reporter: QueryStreamReporter,
+    execution_state: SqlExecutionState,
 ) {
     let mut sql = String::new();
 
@@ -63,36 +104,131 @@ async fn select_builder_stream<
         where_model.as_ref(),
     );
 
+    let params_count = sql_values.len();
+
     let sql = Arc::new(sql);
 
     let sql_spawned = sql.clone();
 
+    let started = Instant::now();
+    #[cfg(feature = "with-logs-and-telemetry")]
+    let started_at = DateTimeAsMicroseconds::now();
+
+    let wait_time = Arc::new(SqlWaitTime::new());
+    let wait_time_spawned = wait_time.clone();
+
+    let queue_guard = reporter.metrics.enter();
+
+    let tx_spawned = tx.clone();
+
     let result = client
         .conn(move |conn| {
-            let mut stmt = conn.prepare(&sql_spawned)?;
-
-            let response = stmt.query_map(sql_values.get_params_to_invoke().as_slice(), |row| {
-                let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
-                TEntity::from(&db_row);
-                Ok(TEntity::from(&db_row))
-            })?;
-
-            for itm in response {
-                let send_result = tx.blocking_send(itm);
-
-                if let Err(err) = send_result {
-                    println!(
-                        "Sending DbRow to string ended with Error. Err:{:?}. Sql:{}",
-                        err, sql_spawned
-                    );
-                }
-            }
+            wait_time_spawned.set_started(started);
+            execution_state.start()?;
+
+            let result = read_rows(conn, &sql_spawned, &sql_values, &tx_spawned);
 
-            Ok(())
+            execution_state.finish();
+
+            result
         })
         .await;
 
+    drop(queue_guard);
+
+    let result = result.map_err(|err| {
+        SqlLiteError::from(err).with_sql(sql.as_str(), reporter.redact_sql_in_errors)
+    });
+
+    let duration = started.elapsed();
+
+    reporter.metrics.record(SqlMetricsRecord {
+        table_name: table_name.as_str(),
+        operation: SqlOperation::SelectStream,
+        duration,
+        wait_time: wait_time.get(duration),
+        rows: result.as_ref().ok().copied(),
+    });
+
+    let level = if result.is_err() {
+        SqlLogLevel::Error
+    } else {
+        SqlLogLevel::Debug
+    };
+
+    if reporter.log_level.is_enabled(level) {
+        let sql_to_log = if reporter.redact_sql_in_errors {
+            crate::redact_sql(sql.as_str())
+        } else {
+            sql.to_string()
+        };
+
+        reporter.logger.log(&SqlLogEvent {
+            level,
+            operation: SqlOperation::SelectStream,
+            table_name: table_name.as_str(),
+            sql: sql_to_log.as_str(),
+            params_count,
+            duration,
+            rows: result.as_ref().ok().copied(),
+            error: result.as_ref().err(),
+            params: None,
+            query_plan: None,
+        });
+    }
+
+    #[cfg(feature = "with-logs-and-telemetry")]
+    SqlRequest {
+        operation: SqlOperation::SelectStream,
+        table_name: table_name.as_str(),
+        telemetry_context: reporter.telemetry_context.as_ref(),
+    }
+    .write_telemetry(
+        reporter.telemetry_sink.as_ref(),
+        started_at,
+        &result,
+        |rows| *rows,
+    );
+
     if let Err(err) = result {
-        println!("Reading stream ended with error. Err:{:?} Sql:{}", err, sql);
+        let _ = tx.send(Err(err)).await;
     }
 }
+
+fn read_rows<TEntity: SelectEntity + Send + Sync + 'static>(
+    conn: &async_sqlite::rusqlite::Connection,
+    sql: &str,
+    sql_values: &SqlValues,
+    tx: &tokio::sync::mpsc::Sender<Result<TEntity, SqlLiteError>>,
+) -> Result<usize, async_sqlite::rusqlite::Error> {
+    let mut stmt = conn.prepare_cached(sql)?;
+
+    let mut rows = stmt.query(sql_values.get_params_to_invoke().as_slice())?;
+
+    let mut row_no = 0;
+
+    loop {
+        let row = match rows.next() {
+            Ok(Some(row)) => row,
+            Ok(None) => break,
+            Err(err) => {
+                if tx.is_closed() {
+                    break;
+                }
+
+                return Err(err);
+            }
+        };
+
+        let db_row = DbRow::new(row, TEntity::SELECT_FIELDS);
+        let itm = TEntity::try_from_db_row(&db_row).map_err(|err| err.into_sql_lite_error(row_no));
+
+        row_no += 1;
+
+        if tx.blocking_send(itm).is_err() {
+            break;
+        }
+    }
+
+    Ok(row_no)
+}
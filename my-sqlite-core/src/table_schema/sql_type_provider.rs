@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, HashMap};
 
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::SqlValueMetadata;
+use crate::{DateTimeStorage, SqlValueMetadata};
 
 use super::TableColumnType;
 
@@ -16,24 +16,12 @@ impl SqlTypeProvider for u8 {
     }
 }
 
-impl SqlTypeProvider for Option<u8> {
-    fn get_sql_type(_metadata: Option<SqlValueMetadata>) -> TableColumnType {
-        TableColumnType::SmallInt
-    }
-}
-
 impl SqlTypeProvider for i8 {
     fn get_sql_type(_metadata: Option<SqlValueMetadata>) -> TableColumnType {
         TableColumnType::SmallInt
     }
 }
 
-impl SqlTypeProvider for Option<i8> {
-    fn get_sql_type(_metadata: Option<SqlValueMetadata>) -> TableColumnType {
-        TableColumnType::SmallInt
-    }
-}
-
 impl SqlTypeProvider for u16 {
     fn get_sql_type(_metadata: Option<SqlValueMetadata>) -> TableColumnType {
         TableColumnType::Integer
@@ -100,12 +88,6 @@ impl SqlTypeProvider for String {
     }
 }
 
-impl SqlTypeProvider for Option<String> {
-    fn get_sql_type(_metadata: Option<SqlValueMetadata>) -> TableColumnType {
-        TableColumnType::Text
-    }
-}
-
 impl<T> SqlTypeProvider for Vec<T> {
     fn get_sql_type(_metadata: Option<SqlValueMetadata>) -> TableColumnType {
         TableColumnType::Json
@@ -132,18 +114,12 @@ impl SqlTypeProvider for bool {
 
 impl SqlTypeProvider for DateTimeAsMicroseconds {
     fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
-        if let Some(metadata) = metadata {
-            if let Some(sql_type) = metadata.sql_type {
-                if sql_type == "timestamp" {
-                    return TableColumnType::Timestamp;
-                }
-
-                if sql_type == "bigint" {
-                    return TableColumnType::BigInt;
-                }
-            }
-        }
-
-        panic!("Sql type is not set")
+        DateTimeStorage::from_metadata(&metadata).get_table_column_type()
+    }
+}
+
+impl<T: SqlTypeProvider> SqlTypeProvider for Option<T> {
+    fn get_sql_type(metadata: Option<SqlValueMetadata>) -> TableColumnType {
+        T::get_sql_type(metadata)
     }
 }
@@ -10,6 +10,7 @@ pub enum TableColumnType {
     Json,
     Timestamp,
     Jsonb,
+    Blob,
 }
 
 impl TableColumnType {
@@ -25,6 +26,7 @@ impl TableColumnType {
             TableColumnType::Json => 7,
             TableColumnType::Timestamp => 8,
             TableColumnType::Jsonb => 8,
+            TableColumnType::Blob => 9,
         }
     }
 
@@ -52,6 +54,7 @@ impl TableColumnType {
             "integer" => Some(TableColumnType::Integer),
             "json" => Some(TableColumnType::Json),
             "jsonb" => Some(TableColumnType::Jsonb),
+            "blob" => Some(TableColumnType::Blob),
             "timestamp" => Some(TableColumnType::Timestamp),
             "character varying" => Some(TableColumnType::Text),
             _ => None,
@@ -70,6 +73,7 @@ impl TableColumnType {
             TableColumnType::Json => "json",
             TableColumnType::Jsonb => "jsonb",
             TableColumnType::Timestamp => "timestamp",
+            TableColumnType::Blob => "blob",
         }
     }
 }
@@ -0,0 +1,28 @@
+use std::sync::Mutex;
+
+use super::{SqlTelemetryEvent, SqlTelemetrySink};
+
+#[derive(Default)]
+pub struct InMemoryTelemetrySink {
+    events: Mutex<Vec<SqlTelemetryEvent>>,
+}
+
+impl InMemoryTelemetrySink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_events(&self) -> Vec<SqlTelemetryEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    pub fn clear(&self) {
+        self.events.lock().unwrap().clear();
+    }
+}
+
+impl SqlTelemetrySink for InMemoryTelemetrySink {
+    fn write(&self, event: SqlTelemetryEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
@@ -0,0 +1,8 @@
+mod sql_telemetry_event;
+pub use sql_telemetry_event::*;
+mod sql_telemetry_sink;
+pub use sql_telemetry_sink::*;
+mod my_telemetry_sink;
+pub use my_telemetry_sink::*;
+mod in_memory_telemetry_sink;
+pub use in_memory_telemetry_sink::*;
@@ -0,0 +1,30 @@
+use super::{SqlTelemetryEvent, SqlTelemetrySink};
+
+pub struct MyTelemetrySink;
+
+impl SqlTelemetrySink for MyTelemetrySink {
+    fn write(&self, event: SqlTelemetryEvent) {
+        tokio::spawn(async move {
+            let event_name = event.get_event_name();
+
+            match event.error {
+                Some(err) => {
+                    my_telemetry::TELEMETRY_INTERFACE
+                        .write_fail(&event.telemetry_context, event.started, event_name, err, None)
+                        .await;
+                }
+                None => {
+                    my_telemetry::TELEMETRY_INTERFACE
+                        .write_success(
+                            &event.telemetry_context,
+                            event.started,
+                            event_name,
+                            format!("Rows: {}", event.rows.unwrap_or_default()),
+                            None,
+                        )
+                        .await;
+                }
+            }
+        });
+    }
+}
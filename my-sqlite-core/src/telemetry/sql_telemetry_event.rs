@@ -0,0 +1,25 @@
+use my_telemetry::MyTelemetryContext;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::SqlOperation;
+
+#[derive(Debug, Clone)]
+pub struct SqlTelemetryEvent {
+    pub telemetry_context: MyTelemetryContext,
+    pub started: DateTimeAsMicroseconds,
+    pub finished: DateTimeAsMicroseconds,
+    pub operation: SqlOperation,
+    pub table_name: String,
+    pub rows: Option<usize>,
+    pub error: Option<String>,
+}
+
+impl SqlTelemetryEvent {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn get_event_name(&self) -> String {
+        format!("sqlite {} {}", self.operation, self.table_name)
+    }
+}
@@ -0,0 +1,5 @@
+use super::SqlTelemetryEvent;
+
+pub trait SqlTelemetrySink: Send + Sync {
+    fn write(&self, event: SqlTelemetryEvent);
+}
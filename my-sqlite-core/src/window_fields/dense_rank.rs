@@ -0,0 +1,46 @@
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct DenseRank(i64);
+
+impl DenseRank {
+    pub fn get_value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl SelectValueProvider for DenseRank {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(crate::sql::SelectFieldValue::Expression {
+            field_name,
+            statement: crate::render_window_statement("dense_rank()", metadata).into(),
+        });
+    }
+}
+
+impl<'s> FromDbRow<'s, DenseRank> for DenseRank {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<DenseRank, DecodeError> {
+        let value: i64 = row.try_get(column_name.db_column_name)?;
+        Ok(DenseRank(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<DenseRank>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(DenseRank))
+    }
+}
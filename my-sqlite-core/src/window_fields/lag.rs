@@ -0,0 +1,56 @@
+use async_sqlite::rusqlite::types::FromSql;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct Lag<T>(Option<T>);
+
+impl<T: Copy> Lag<T> {
+    pub fn get_value(&self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Lag<T> {
+    pub fn into_value(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> SelectValueProvider for Lag<T> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let function = format!("lag({})", field_name.db_column_name);
+
+        sql.push(crate::sql::SelectFieldValue::Expression {
+            field_name,
+            statement: crate::render_window_statement(function.as_str(), metadata).into(),
+        });
+    }
+}
+
+impl<'s, T: FromSql> FromDbRow<'s, Lag<T>> for Lag<T> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Lag<T>, DecodeError> {
+        let value: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(Lag(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<Lag<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(|value| Lag(Some(value))))
+    }
+}
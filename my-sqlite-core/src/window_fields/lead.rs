@@ -0,0 +1,56 @@
+use async_sqlite::rusqlite::types::FromSql;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct Lead<T>(Option<T>);
+
+impl<T: Copy> Lead<T> {
+    pub fn get_value(&self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Lead<T> {
+    pub fn into_value(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> SelectValueProvider for Lead<T> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let function = format!("lead({})", field_name.db_column_name);
+
+        sql.push(crate::sql::SelectFieldValue::Expression {
+            field_name,
+            statement: crate::render_window_statement(function.as_str(), metadata).into(),
+        });
+    }
+}
+
+impl<'s, T: FromSql> FromDbRow<'s, Lead<T>> for Lead<T> {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Lead<T>, DecodeError> {
+        let value: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(Lead(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<Lead<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(|value| Lead(Some(value))))
+    }
+}
@@ -0,0 +1,14 @@
+mod window_statement;
+pub use window_statement::*;
+mod row_number;
+pub use row_number::*;
+mod rank;
+pub use rank::*;
+mod dense_rank;
+pub use dense_rank::*;
+mod lag;
+pub use lag::*;
+mod lead;
+pub use lead::*;
+mod running_sum;
+pub use running_sum::*;
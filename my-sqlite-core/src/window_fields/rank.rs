@@ -0,0 +1,46 @@
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct Rank(i64);
+
+impl Rank {
+    pub fn get_value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl SelectValueProvider for Rank {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(crate::sql::SelectFieldValue::Expression {
+            field_name,
+            statement: crate::render_window_statement("rank()", metadata).into(),
+        });
+    }
+}
+
+impl<'s> FromDbRow<'s, Rank> for Rank {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Rank, DecodeError> {
+        let value: i64 = row.try_get(column_name.db_column_name)?;
+        Ok(Rank(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<Rank>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(Rank))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, SqlValueMetadata,
+};
+
+pub struct RowNumber(i64);
+
+impl RowNumber {
+    pub fn get_value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl SelectValueProvider for RowNumber {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        sql.push(crate::sql::SelectFieldValue::Expression {
+            field_name,
+            statement: crate::render_window_statement("row_number()", metadata).into(),
+        });
+    }
+}
+
+impl<'s> FromDbRow<'s, RowNumber> for RowNumber {
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<RowNumber, DecodeError> {
+        let value: i64 = row.try_get(column_name.db_column_name)?;
+        Ok(RowNumber(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<RowNumber>, DecodeError> {
+        let result: Option<i64> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(RowNumber))
+    }
+}
@@ -0,0 +1,67 @@
+use async_sqlite::rusqlite::types::FromSql;
+
+use crate::{
+    sql::SelectBuilder,
+    sql_select::{DbColumnName, FromDbRow, SelectValueProvider},
+    DecodeError, GroupByFieldType, SqlValueMetadata,
+};
+
+pub struct RunningSum<T: Send + Sync + 'static>(T);
+
+impl<T: Copy + FromSql + Send + Sync + 'static> RunningSum<T> {
+    pub fn get_value(&self) -> T {
+        self.0
+    }
+}
+
+impl<T: GroupByFieldType + Send + Sync + 'static> SelectValueProvider for RunningSum<T> {
+    fn fill_select_part(
+        sql: &mut SelectBuilder,
+        field_name: DbColumnName,
+        metadata: &Option<SqlValueMetadata>,
+    ) {
+        let sql_type = if let Some(metadata) = metadata {
+            if let Some(sql_type) = metadata.sql_type {
+                sql_type
+            } else {
+                T::DB_SQL_TYPE
+            }
+        } else {
+            T::DB_SQL_TYPE
+        };
+
+        let function = format!("SUM({})", field_name.db_column_name);
+
+        sql.push(crate::sql::SelectFieldValue::Expression {
+            field_name,
+            statement: format!(
+                "cast({} as {})",
+                crate::render_window_statement(function.as_str(), metadata),
+                sql_type
+            )
+            .into(),
+        });
+    }
+}
+
+impl<'s, T: Copy + FromSql + Send + Sync + 'static> FromDbRow<'s, RunningSum<T>>
+    for RunningSum<T>
+{
+    fn try_from_db_row(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<RunningSum<T>, DecodeError> {
+        let value: T = row.try_get(column_name.db_column_name)?;
+        Ok(RunningSum(value))
+    }
+
+    fn try_from_db_row_opt(
+        row: &'s crate::DbRow,
+        column_name: DbColumnName,
+        _metadata: &Option<SqlValueMetadata>,
+    ) -> Result<Option<RunningSum<T>>, DecodeError> {
+        let result: Option<T> = row.try_get(column_name.db_column_name)?;
+        Ok(result.map(RunningSum))
+    }
+}
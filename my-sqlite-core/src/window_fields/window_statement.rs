@@ -0,0 +1,10 @@
+use crate::SqlValueMetadata;
+
+pub fn render_window_statement(function: &str, metadata: &Option<SqlValueMetadata>) -> String {
+    let window = metadata
+        .as_ref()
+        .and_then(|metadata| metadata.window)
+        .unwrap_or("");
+
+    format!("{} OVER ({})", function, window)
+}
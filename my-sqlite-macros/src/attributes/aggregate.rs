@@ -0,0 +1,8 @@
+use types_reader::macros::*;
+
+#[attribute_name("aggregate")]
+#[derive(MacrosParameters)]
+pub struct AggregateAttribute<'s> {
+    #[default]
+    pub expression: &'s str,
+}
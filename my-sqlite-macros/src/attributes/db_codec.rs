@@ -0,0 +1,20 @@
+use types_reader::macros::*;
+
+#[attribute_name("db_codec")]
+#[derive(MacrosParameters)]
+pub struct DbCodecAttribute {
+    pub with: String,
+}
+
+impl DbCodecAttribute {
+    pub fn get_codec_path(&self) -> Result<syn::Path, syn::Error> {
+        syn::parse_str(self.with.as_str())
+    }
+
+    pub fn generate_attribute(&self) -> proc_macro2::TokenStream {
+        let with = self.with.as_str();
+        quote::quote! {
+            #[db_codec(with = #with)]
+        }
+    }
+}
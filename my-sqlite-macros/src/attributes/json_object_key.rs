@@ -0,0 +1,8 @@
+use types_reader::macros::*;
+
+#[attribute_name("json_object_key")]
+#[derive(MacrosParameters)]
+pub struct JsonObjectKeyAttribute<'s> {
+    #[default]
+    pub column_name: &'s str,
+}
@@ -28,3 +28,15 @@ mod ignore;
 pub use ignore::*;
 mod ignore_table_column;
 pub use ignore_table_column::*;
+mod aggregate;
+pub use aggregate::*;
+mod separator;
+pub use separator::*;
+mod json_object_key;
+pub use json_object_key::*;
+mod time_bucket;
+pub use time_bucket::*;
+mod window;
+pub use window::*;
+mod db_codec;
+pub use db_codec::*;
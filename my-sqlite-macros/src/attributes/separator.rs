@@ -0,0 +1,8 @@
+use types_reader::macros::*;
+
+#[attribute_name("separator")]
+#[derive(MacrosParameters)]
+pub struct SeparatorAttribute<'s> {
+    #[default]
+    pub value: &'s str,
+}
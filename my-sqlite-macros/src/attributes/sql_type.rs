@@ -4,10 +4,44 @@ use types_reader::macros::*;
 pub enum SqlType {
     #[value("bigint")]
     Bigint,
+    #[value("unix_microseconds")]
+    UnixMicroseconds,
+    #[value("unix_milliseconds")]
+    UnixMilliseconds,
+    #[value("unix_seconds")]
+    UnixSeconds,
     #[value("timestamp")]
     Timestamp,
+    #[value("timestamp_milliseconds")]
+    TimestampMilliseconds,
+    #[value("timestamp_seconds")]
+    TimestampSeconds,
+    #[value("julianday")]
+    JulianDay,
     #[value("jsonb")]
     JsonB,
+    #[value("text")]
+    Text,
+    #[value("blob")]
+    Blob,
+    #[value("real")]
+    Real,
+}
+
+impl SqlType {
+    pub fn is_date_time_storage(&self) -> bool {
+        match self {
+            Self::Bigint
+            | Self::UnixMicroseconds
+            | Self::UnixMilliseconds
+            | Self::UnixSeconds
+            | Self::Timestamp
+            | Self::TimestampMilliseconds
+            | Self::TimestampSeconds
+            | Self::JulianDay => true,
+            Self::JsonB | Self::Text | Self::Blob | Self::Real => false,
+        }
+    }
 }
 
 #[attribute_name("sql_type")]
@@ -0,0 +1,39 @@
+use types_reader::macros::*;
+
+#[attribute_name("time_bucket")]
+#[derive(MacrosParameters)]
+pub struct TimeBucketAttribute<'s> {
+    #[default]
+    pub value: &'s str,
+}
+
+impl<'s> TimeBucketAttribute<'s> {
+    // Mirrors my_sqlite::TimeBucket::parse, which the macros crate can not depend on.
+    pub fn is_valid(&self) -> bool {
+        if matches!(self.value, "minute" | "hour" | "day") {
+            return true;
+        }
+
+        let (value, multiplier) = if let Some(value) = self.value.strip_suffix('s') {
+            (value, 1_i64)
+        } else if let Some(value) = self.value.strip_suffix('m') {
+            (value, 60)
+        } else if let Some(value) = self.value.strip_suffix('h') {
+            (value, 3_600)
+        } else if let Some(value) = self.value.strip_suffix('d') {
+            (value, 86_400)
+        } else {
+            return false;
+        };
+
+        let Ok(value) = value.parse::<i64>() else {
+            return false;
+        };
+
+        value > 0
+            && value
+                .checked_mul(multiplier)
+                .and_then(|seconds| seconds.checked_mul(1_000_000))
+                .is_some()
+    }
+}
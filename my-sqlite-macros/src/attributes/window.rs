@@ -0,0 +1,8 @@
+use types_reader::macros::*;
+
+#[attribute_name("window")]
+#[derive(MacrosParameters)]
+pub struct WindowAttribute<'s> {
+    #[default]
+    pub value: &'s str,
+}
@@ -87,11 +87,11 @@ pub fn generate(
 
     let from_db_result = if type_name.to_string() == sql_db_type.to_string() {
         quote! {
-            Self::from_db_value(result)
+            Self::try_from_db_value(result)
         }
     } else {
         quote! {
-            Self::from_db_value(result as #type_name)
+            Self::try_from_db_value(result as #type_name)
         }
     };
 
@@ -135,6 +135,15 @@ pub fn generate(
                 }
             }
 
+            pub fn try_from_db_value(src: #type_name)->Option<Self>{
+                let result = match src{
+                  #(#from_db_value)*
+                  _ => return None
+                };
+
+                Some(result)
+            }
+
             pub fn fill_select_part(sql: &mut  my_sqlite::sql::SelectBuilder, field_name: my_sqlite::sql_select::DbColumnName, metadata: &Option<my_sqlite::SqlValueMetadata>) {
                 sql.push(my_sqlite::sql::SelectFieldValue::Field(field_name));
             }
@@ -154,15 +163,25 @@ pub fn generate(
         #impl_where_value_provider
 
         impl<'s> my_sqlite::sql_select::FromDbRow<'s, #enum_name> for #enum_name{
-            fn from_db_row(row: &'s my_sqlite::DbRow, column_name: #db_field_type,  metadata: &Option<my_sqlite::SqlValueMetadata>) -> Self{
-                let result: #sql_db_type = row.get(field_name.db_column_name);
-                #from_db_result
+            fn try_from_db_row(row: &'s my_sqlite::DbRow, column_name: #db_field_type,  metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Self, my_sqlite::DecodeError>{
+                let result: #sql_db_type = row.try_get(column_name.db_column_name)?;
+                match #from_db_result{
+                    Some(value) => Ok(value),
+                    None => Err(my_sqlite::DecodeError::invalid_value(column_name.db_column_name, format!("Invalid value {}", result))),
+                }
             }
 
-            fn from_db_row_opt(row: &'s my_sqlite::DbRow, column_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Option<Self>{
-                let result: Option<#sql_db_type> = row.get(field_name.db_column_name);
-                let result = result?;
-                Some(#from_db_result)
+            fn try_from_db_row_opt(row: &'s my_sqlite::DbRow, column_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Option<Self>, my_sqlite::DecodeError>{
+                let result: Option<#sql_db_type> = row.try_get(column_name.db_column_name)?;
+                let result = match result{
+                    Some(result) => result,
+                    None => return Ok(None),
+                };
+
+                match #from_db_result{
+                    Some(value) => Ok(Some(value)),
+                    None => Err(my_sqlite::DecodeError::invalid_value(column_name.db_column_name, format!("Invalid value {}", result))),
+                }
             }
         }
 
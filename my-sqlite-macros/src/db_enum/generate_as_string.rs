@@ -47,6 +47,15 @@ pub fn generate_as_string(ast: &syn::DeriveInput) -> Result<proc_macro::TokenStr
                 }
             }
 
+            pub fn try_from_str(src: &str)->Option<Self>{
+                let result = match src{
+                    #fn_from_str
+                  _ => return None
+                };
+
+                Some(result)
+            }
+
             pub fn fill_select_part(sql: &mut my_sqlite::sql::SelectBuilder, field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) {
                 sql.push(my_sqlite::sql::SelectFieldValue::Field(field_name));
             }
@@ -67,15 +76,25 @@ pub fn generate_as_string(ast: &syn::DeriveInput) -> Result<proc_macro::TokenStr
         #impl_where_value_provider
 
         impl<'s> my_sqlite::sql_select::FromDbRow<'s, #enum_name> for #enum_name{
-            fn from_db_row(row: &'s my_sqlite::DbRow,  field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Self{
-                let result: String = row.get(field_name.db_column_name);
-                Self::from_str(result.as_str())
+            fn try_from_db_row(row: &'s my_sqlite::DbRow,  field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Self, my_sqlite::DecodeError>{
+                let result: String = row.try_get(field_name.db_column_name)?;
+                match Self::try_from_str(result.as_str()){
+                    Some(value) => Ok(value),
+                    None => Err(my_sqlite::DecodeError::invalid_value(field_name.db_column_name, format!("Invalid value {}", result))),
+                }
             }
 
-            fn from_db_row_opt(row: &'s my_sqlite::DbRow,  field_name: #db_field_type,  metadata: &Option<my_sqlite::SqlValueMetadata>) -> Option<Self>{
-                let result: Option<String> = row.get(field_name.db_column_name);
-                let result = result?;
-                Some(Self::from_str(result.as_str()))
+            fn try_from_db_row_opt(row: &'s my_sqlite::DbRow,  field_name: #db_field_type,  metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Option<Self>, my_sqlite::DecodeError>{
+                let result: Option<String> = row.try_get(field_name.db_column_name)?;
+                let result = match result{
+                    Some(result) => result,
+                    None => return Ok(None),
+                };
+
+                match Self::try_from_str(result.as_str()){
+                    Some(value) => Ok(Some(value)),
+                    None => Err(my_sqlite::DecodeError::invalid_value(field_name.db_column_name, format!("Invalid value {}", result))),
+                }
             }
         }
 
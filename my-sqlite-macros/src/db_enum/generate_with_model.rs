@@ -58,14 +58,14 @@ pub fn generate_with_model(ast: &syn::DeriveInput) -> Result<TokenStream, syn::E
        
 
         impl<'s> my_sqlite::sql_select::FromDbRow<'s, #enum_name> for #enum_name{
-            fn from_db_row(row: &'s my_sqlite::DbRow, field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Self{
-                let value: String = row.get(field_name.db_column_name);
-                Self::from_db_value(value.as_str())
+            fn try_from_db_row(row: &'s my_sqlite::DbRow, field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Self, my_sqlite::DecodeError>{
+                let value: String = row.try_get(field_name.db_column_name)?;
+                Ok(Self::from_db_value(value.as_str()))
             }
 
-            fn from_db_row_opt(row: &'s my_sqlite::DbRow, field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Option<Self>{
-                let value: Option<String> = row.get(field_name.db_column_name);
-                Self::from_db_value(value?.as_str()).into()
+            fn try_from_db_row_opt(row: &'s my_sqlite::DbRow, field_name: #db_field_type, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Option<Self>, my_sqlite::DecodeError>{
+                let value: Option<String> = row.try_get(field_name.db_column_name)?;
+                Ok(value.map(|value| Self::from_db_value(value.as_str())))
             }
         }
 
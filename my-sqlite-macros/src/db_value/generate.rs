@@ -0,0 +1,177 @@
+use quote::quote;
+use types_reader::{MacrosAttribute, TokensObject};
+
+use crate::attributes::DbCodecAttribute;
+
+enum DbValueSource {
+    Inner(syn::Type),
+    StructCodec(syn::Path),
+    FieldCodec(syn::Path),
+}
+
+pub fn generate(ast: &syn::DeriveInput) -> Result<proc_macro::TokenStream, syn::Error> {
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "DbValue can not be derived for generic types",
+        ));
+    }
+
+    let struct_name = &ast.ident;
+
+    let source = get_db_value_source(ast)?;
+
+    let db_field_type = crate::utils::get_column_type_as_parameter();
+
+    let (get_sql_type, fill_select_part, from_db_row, from_db_row_opt, encoded_value) =
+        match &source {
+            DbValueSource::Inner(inner_type) => (
+                quote! {
+                    use my_sqlite::table_schema::SqlTypeProvider;
+                    <#inner_type>::get_sql_type(metadata)
+                },
+                quote! {
+                    use my_sqlite::sql_select::SelectValueProvider;
+                    <#inner_type>::fill_select_part(sql, field_name, metadata)
+                },
+                quote! {
+                    use my_sqlite::sql_select::FromDbRow;
+                    Ok(Self(<#inner_type>::try_from_db_row(row, column_name, metadata)?))
+                },
+                quote! {
+                    use my_sqlite::sql_select::FromDbRow;
+                    Ok(<#inner_type>::try_from_db_row_opt(row, column_name, metadata)?.map(Self))
+                },
+                quote!(&self.0),
+            ),
+            DbValueSource::StructCodec(codec) => (
+                quote!(my_sqlite::codec_get_sql_type(#codec::encode, metadata)),
+                quote!(my_sqlite::codec_fill_select_part(#codec::encode, sql, field_name, metadata)),
+                quote!(my_sqlite::codec_from_db_row(#codec::decode, row, column_name, metadata)),
+                quote!(my_sqlite::codec_from_db_row_opt(#codec::decode, row, column_name, metadata)),
+                quote!(&#codec::encode(self)),
+            ),
+            DbValueSource::FieldCodec(codec) => (
+                quote!(my_sqlite::codec_get_sql_type(#codec::encode, metadata)),
+                quote!(my_sqlite::codec_fill_select_part(#codec::encode, sql, field_name, metadata)),
+                quote!(Ok(Self(my_sqlite::codec_from_db_row(#codec::decode, row, column_name, metadata)?))),
+                quote!(Ok(my_sqlite::codec_from_db_row_opt(#codec::decode, row, column_name, metadata)?.map(Self))),
+                quote!(&#codec::encode(&self.0)),
+            ),
+        };
+
+    let result = quote! {
+        impl my_sqlite::table_schema::SqlTypeProvider for #struct_name {
+            fn get_sql_type(
+                metadata: Option<my_sqlite::SqlValueMetadata>,
+            ) -> my_sqlite::table_schema::TableColumnType {
+                #get_sql_type
+            }
+        }
+
+        impl my_sqlite::sql_select::SelectValueProvider for #struct_name {
+            fn fill_select_part(
+                sql: &mut my_sqlite::sql::SelectBuilder,
+                field_name: #db_field_type,
+                metadata: &Option<my_sqlite::SqlValueMetadata>,
+            ) {
+                #fill_select_part
+            }
+        }
+
+        impl<'s> my_sqlite::sql_select::FromDbRow<'s, #struct_name> for #struct_name {
+            fn try_from_db_row(
+                row: &'s my_sqlite::DbRow,
+                column_name: #db_field_type,
+                metadata: &Option<my_sqlite::SqlValueMetadata>,
+            ) -> Result<Self, my_sqlite::DecodeError> {
+                #from_db_row
+            }
+
+            fn try_from_db_row_opt(
+                row: &'s my_sqlite::DbRow,
+                column_name: #db_field_type,
+                metadata: &Option<my_sqlite::SqlValueMetadata>,
+            ) -> Result<Option<Self>, my_sqlite::DecodeError> {
+                #from_db_row_opt
+            }
+        }
+
+        impl my_sqlite::sql_update::SqlUpdateValueProvider for #struct_name {
+            fn get_update_value(
+                &self,
+                params: &mut my_sqlite::sql::SqlValues,
+                metadata: &Option<my_sqlite::SqlValueMetadata>,
+            ) -> my_sqlite::sql::SqlUpdateValue {
+                my_sqlite::sql_update::SqlUpdateValueProvider::get_update_value(#encoded_value, params, metadata)
+            }
+        }
+
+        impl my_sqlite::SqlWhereValueProvider for #struct_name {
+            fn fill_where_value(
+                &self,
+                full_where_condition: Option<my_sqlite::RenderFullWhereCondition>,
+                sql: &mut String,
+                params: &mut my_sqlite::sql::SqlValues,
+                metadata: &Option<my_sqlite::SqlValueMetadata>,
+            ) -> bool {
+                my_sqlite::SqlWhereValueProvider::fill_where_value(#encoded_value, full_where_condition, sql, params, metadata)
+            }
+
+            fn render_value(&self) -> bool {
+                my_sqlite::SqlWhereValueProvider::render_value(#encoded_value)
+            }
+        }
+    }
+    .into();
+
+    Ok(result)
+}
+
+fn get_db_value_source(ast: &syn::DeriveInput) -> Result<DbValueSource, syn::Error> {
+    let struct_codec = read_db_codec(&ast.attrs)?;
+
+    let inner_field = match &ast.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                fields.unnamed.first()
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if let Some(struct_codec) = struct_codec {
+        return Ok(DbValueSource::StructCodec(struct_codec));
+    }
+
+    let inner_field = match inner_field {
+        Some(inner_field) => inner_field,
+        None => {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                "DbValue requires a newtype struct Name(Inner) or #[db_codec(with = \"codec\")] attribute",
+            ))
+        }
+    };
+
+    if let Some(field_codec) = read_db_codec(&inner_field.attrs)? {
+        return Ok(DbValueSource::FieldCodec(field_codec));
+    }
+
+    Ok(DbValueSource::Inner(inner_field.ty.clone()))
+}
+
+fn read_db_codec(attrs: &[syn::Attribute]) -> Result<Option<syn::Path>, syn::Error> {
+    for attr in attrs {
+        if !attr.path().is_ident(DbCodecAttribute::NAME) {
+            continue;
+        }
+
+        let params = TokensObject::new(attr.meta.require_list()?.tokens.clone().into())?;
+        let attr: DbCodecAttribute = (&params).try_into()?;
+        return Ok(Some(attr.get_codec_path()?));
+    }
+
+    Ok(None)
+}
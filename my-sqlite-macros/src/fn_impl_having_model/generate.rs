@@ -0,0 +1,42 @@
+use quote::quote;
+use types_reader::StructureSchema;
+
+use crate::{
+    attributes::AggregateAttribute, struct_ext::StructPropertyExt, where_fields::WhereFields,
+};
+
+pub fn generate(ast: &syn::DeriveInput) -> Result<proc_macro::TokenStream, syn::Error> {
+    let structure_schema = StructureSchema::new(ast)?;
+
+    let where_fields = WhereFields::new(&structure_schema);
+
+    if let Some(prop) = where_fields.limit.or(where_fields.offset) {
+        return Err(syn::Error::new_spanned(
+            prop.field,
+            "HavingDbModel does not support limit or offset fields",
+        ));
+    }
+
+    let having_data = where_fields.fn_fill_condition_content(|prop| {
+        let aggregate: Option<AggregateAttribute> = prop.try_get_attribute()?;
+
+        match aggregate {
+            Some(aggregate) => Ok(aggregate.expression.to_string()),
+            None => Ok(prop.get_db_column_name()?.as_str().to_string()),
+        }
+    })?;
+
+    let result = crate::render_impl::impl_sql_having_model(
+        &structure_schema.name,
+        quote! {
+            use my_sqlite::SqlWhereValueProvider;
+            #having_data
+        },
+        where_fields.generate_has_conditions_fn(),
+    );
+
+    Ok(quote! {
+        #result
+    }
+    .into())
+}
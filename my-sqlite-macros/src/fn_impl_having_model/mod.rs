@@ -0,0 +1,2 @@
+mod generate;
+pub use generate::*;
@@ -32,21 +32,48 @@ pub fn fn_get_order_by_fields<'s>(
     }
 
     let mut result = String::new();
-    result.push_str(" ORDER BY");
+    result.push_str(" ORDER BY ");
 
-    if !order_by_desc.is_empty() {
-        for field in order_by_desc {
-            result.push(' ');
-            let db_column_name = field.get_db_column_name()?;
-            result.push_str(db_column_name.as_str());
-        }
-        result.push_str(" DESC");
-    } else if !order_by.is_empty() {
-        for field in order_by {
-            result.push(' ');
-            result.push_str(field.get_db_column_name()?.as_str());
+    let (fields, suffix) = if !order_by_desc.is_empty() {
+        (order_by_desc, " DESC")
+    } else {
+        (order_by, "")
+    };
+
+    for (no, field) in fields.into_iter().enumerate() {
+        if no > 0 {
+            result.push(',');
         }
+
+        result.push_str(field.get_db_column_name()?.as_str());
+        result.push_str(suffix);
     }
 
     return Ok(quote!(Some(#result)).into());
 }
+
+// query_page_by_cursor pages by the ORDER BY values, so they must be present,
+// not nullable and address single rows (no GROUP BY).
+pub fn is_cursor_pageable<'s>(fields: &'s impl StructSchema<'s>) -> bool {
+    let mut has_order_by = false;
+
+    for prop in fields.get_fields() {
+        if prop.attrs.has_attr("group_by") {
+            return false;
+        }
+
+        if prop.ty.get_token_stream().to_string().contains("GroupBy") {
+            return false;
+        }
+
+        if prop.attrs.has_attr("order_by") || prop.attrs.has_attr("order_by_desc") {
+            if prop.ty.is_option() {
+                return false;
+            }
+
+            has_order_by = true;
+        }
+    }
+
+    has_order_by
+}
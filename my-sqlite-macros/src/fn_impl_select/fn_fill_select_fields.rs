@@ -1,6 +1,6 @@
-use types_reader::PropertyType;
-
-use crate::{struct_ext::StructPropertyExt, struct_schema::StructSchema};
+use crate::{
+    attributes::WindowAttribute, struct_ext::StructPropertyExt, struct_schema::StructSchema,
+};
 use quote::quote;
 
 pub fn fn_fill_select_fields<'s>(
@@ -16,31 +16,41 @@ pub fn fn_fill_select_fields<'s>(
             continue;
         }
 
+        let db_column_name = prop.get_db_column_name()?;
+        let db_row_column_name = db_column_name.get_db_row_column_name();
+        let db_row_column_name = db_row_column_name.as_str();
+
         if let Ok(sql) = prop.attrs.get_single_or_named_param("sql", "sql") {
-            let attr_value = sql.as_string()?.as_str();
+            let mut statement = sql.as_string()?.as_str().to_string();
+
+            let window: Option<WindowAttribute> = prop.try_get_attribute()?;
+
+            if let Some(window) = window {
+                statement.push_str(" OVER (");
+                statement.push_str(window.value);
+                statement.push(')');
+            }
+
+            let db_column_name = db_column_name.to_column_name_token();
+
             result.push(quote! {
-                sql.push_str(#attr_value);
+                sql.push(my_sqlite::sql::SelectFieldValue::Expression{
+                    field_name: #db_column_name,
+                    statement: #statement.into(),
+                });
             });
         } else {
-            let db_column_name = prop.get_db_column_name()?;
-            let db_row_column_name = db_column_name.get_db_row_column_name();
-            let db_row_column_name = db_row_column_name.as_str();
-
             let db_column_name = db_column_name.to_column_name_token();
 
             let metadata = prop.get_field_metadata()?;
 
-            if let PropertyType::OptionOf(sub_type) = &prop.ty {
-                let type_ident = sub_type.get_token_stream_with_generics();
-
-                result.push(
-                    quote! {
-                        #type_ident::fill_select_part(sql, #db_column_name, &#metadata);
-                    }
-                    .into(),
-                );
+            if let Some(codec) = prop.get_db_codec()? {
+                result.push(quote! {
+                    my_sqlite::codec_fill_select_part(#codec::encode, sql, #db_column_name, &#metadata);
+                });
             } else {
-                let type_ident = prop.ty.get_token_stream_with_generics();
+                let type_ident = prop.get_value_type_token();
+
                 result.push(
                     quote! {
                         #type_ident::fill_select_part(sql, #db_column_name, &#metadata);
@@ -48,9 +58,9 @@ pub fn fn_fill_select_fields<'s>(
                     .into(),
                 );
             }
-
-            result_2.push(quote!(#db_row_column_name,));
         }
+
+        result_2.push(quote!(#db_row_column_name,));
     }
 
     Ok((result, result_2))
@@ -15,12 +15,18 @@ pub fn fn_from<'s>(
 
         let metadata = field.get_field_metadata()?;
 
-        let reading = if let PropertyType::OptionOf(sub_prop) = &field.ty {
-            let type_ident = sub_prop.get_token_stream_with_generics();
-            quote!(#type_ident::from_db_row_opt(db_row, #db_column_name, &#metadata))
+        let reading = if let Some(codec) = field.get_db_codec()? {
+            if let PropertyType::OptionOf(_) = &field.ty {
+                quote!(my_sqlite::codec_from_db_row_opt(#codec::decode, db_row, #db_column_name, &#metadata)?)
+            } else {
+                quote!(my_sqlite::codec_from_db_row(#codec::decode, db_row, #db_column_name, &#metadata)?)
+            }
+        } else if let PropertyType::OptionOf(_) = &field.ty {
+            let type_ident = field.get_value_type_token();
+            quote!(#type_ident::try_from_db_row_opt(db_row, #db_column_name, &#metadata)?)
         } else {
-            let type_ident = field.ty.get_token_stream_with_generics();
-            quote!(#type_ident::from_db_row(db_row, #db_column_name, &#metadata))
+            let type_ident = field.get_value_type_token();
+            quote!(#type_ident::try_from_db_row(db_row, #db_column_name, &#metadata)?)
         };
 
         result.push(quote! {
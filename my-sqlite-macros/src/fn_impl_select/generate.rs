@@ -26,6 +26,14 @@ pub fn generate(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
 
     let struct_name = structure_schema.name.get_name_ident();
 
+    let cursor_page_entity = if super::fn_fill_order_by::is_cursor_pageable(&structure_schema) {
+        quote! {
+            impl my_sqlite::sql_select::CursorPageEntity for #struct_name {}
+        }
+    } else {
+        quote! {}
+    };
+
     let result = quote! {
         impl my_sqlite::sql_select::SelectEntity for #struct_name{
 
@@ -46,14 +54,15 @@ pub fn generate(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
                #group_by_fields
             }
 
-            fn from(db_row: &my_sqlite::DbRow) -> Self {
+            fn try_from_db_row(db_row: &my_sqlite::DbRow) -> Result<Self, my_sqlite::DecodeError> {
                 use my_sqlite::sql_select::FromDbRow;
-                Self{
+                Ok(Self{
                  #(#from_fields)*
-                }
+                })
             }
         }
 
+        #cursor_page_entity
     }
     .into();
 
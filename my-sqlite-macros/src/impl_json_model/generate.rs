@@ -10,6 +10,10 @@ pub fn generate(ast: &syn::DeriveInput) -> Result<proc_macro::TokenStream, syn::
                 serde_json::from_str(src).unwrap()
             }
 
+            pub fn try_from_str(src:&str)->Result<Self, serde_json::Error>{
+                serde_json::from_str(src)
+            }
+
             pub fn to_string(&self)->String{
                 serde_json::to_string(self).unwrap()
             }
@@ -27,17 +31,27 @@ pub fn generate(ast: &syn::DeriveInput) -> Result<proc_macro::TokenStream, syn::
         &type_name,
         || {
             quote::quote! {
-                let str_value: String = row.get(name);
-                Self::from_str(str_value.as_str())
+                let str_value: String = row.try_get(column_name.db_column_name)?;
+
+                match Self::try_from_str(str_value.as_str()) {
+                    Ok(result) => Ok(result),
+                    Err(err) => Err(my_sqlite::DecodeError::json(column_name.db_column_name, err)),
+                }
             }
         },
         || {
             quote::quote! {
-                let str_value: Option<String> = row.get(name);
-                let str_value = str_value.as_ref()?;
+                let str_value: Option<String> = row.try_get(column_name.db_column_name)?;
+
+                let str_value = match str_value {
+                    Some(str_value) => str_value,
+                    None => return Ok(None),
+                };
 
-                let result = Self::from_str(str_value);
-                Some(result)
+                match Self::try_from_str(str_value.as_str()) {
+                    Ok(result) => Ok(Some(result)),
+                    Err(err) => Err(my_sqlite::DecodeError::json(column_name.db_column_name, err)),
+                }
             }
         },
     );
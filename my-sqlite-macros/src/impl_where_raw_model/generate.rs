@@ -3,7 +3,11 @@ use std::collections::HashMap;
 use sql_core::sql_with_placeholders::*;
 use types_reader::{StructureSchema, TokensObject, TypeName};
 
-use crate::{struct_ext::StructPropertyExt, where_fields::WhereFields};
+use crate::{
+    attributes::{WhereOperator, WhereOperatorAttribute},
+    struct_ext::StructPropertyExt,
+    where_fields::WhereFields,
+};
 
 pub fn generate_where_raw_model<'s>(
     attr: proc_macro::TokenStream,
@@ -49,6 +53,24 @@ pub fn generate_where_raw_model<'s>(
 
                 let property = property.unwrap();
 
+                let operator: Option<WhereOperatorAttribute> = property.try_get_attribute()?;
+
+                let is_json_contains = match &operator {
+                    Some(operator) => matches!(operator.op, WhereOperator::JsonContains),
+                    None => false,
+                } || property
+                    .ty
+                    .get_token_stream()
+                    .to_string()
+                    .contains("JsonArrayContains");
+
+                if is_json_contains {
+                    return Err(syn::Error::new_spanned(
+                        property.field,
+                        "json_contains requires a column name and is not supported by WhereRawModel",
+                    ));
+                }
+
                 let name = property.get_field_name_ident();
                 let meta_data = property.get_field_metadata()?;
 
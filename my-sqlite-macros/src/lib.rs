@@ -1,5 +1,7 @@
 mod attributes;
 mod db_enum;
+mod db_value;
+mod fn_impl_having_model;
 mod fn_impl_insert;
 mod fn_impl_select;
 mod fn_impl_update;
@@ -30,9 +32,11 @@ use proc_macro::TokenStream;
         default_value,
         wrap_column_name,
         db_column_name,
+        json,
         generate_select_model,
         generate_update_model,
         generate_where_model,
+        db_codec,
     )
 )]
 pub fn table_schema(input: TokenStream) -> TokenStream {
@@ -61,6 +65,7 @@ pub fn table_schema(input: TokenStream) -> TokenStream {
         ignore_if_none,
         wrap_column_name,
         json,
+        db_codec,
     )
 )]
 pub fn update_db_entity(input: TokenStream) -> TokenStream {
@@ -84,6 +89,12 @@ pub fn update_db_entity(input: TokenStream) -> TokenStream {
         primary_key,
         default_if_null,
         wrap_column_name,
+        json,
+        separator,
+        json_object_key,
+        time_bucket,
+        window,
+        db_codec,
     )
 )]
 pub fn select_db_entity(input: TokenStream) -> TokenStream {
@@ -107,6 +118,7 @@ pub fn select_db_entity(input: TokenStream) -> TokenStream {
         default_if_null,
         ignore_if_none,
         wrap_column_name,
+        db_codec,
     )
 )]
 pub fn insert_db_entity(input: TokenStream) -> TokenStream {
@@ -130,7 +142,9 @@ pub fn insert_db_entity(input: TokenStream) -> TokenStream {
         sql_type,
         default_if_null,
         wrap_column_name,
-        inside_json
+        inside_json,
+        json,
+        db_codec,
     )
 )]
 pub fn where_db_model(input: TokenStream) -> TokenStream {
@@ -141,6 +155,26 @@ pub fn where_db_model(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(
+    HavingDbModel,
+    attributes(
+        aggregate,
+        db_column_name,
+        operator,
+        ignore_if_none,
+        ignore,
+        sql_type,
+        wrap_column_name,
+    )
+)]
+pub fn having_db_model(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).unwrap();
+    match crate::fn_impl_having_model::generate(&ast) {
+        Ok(result) => result,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_derive(DbEnumAsString, attributes(enum_case, default_if_null, default_value,))]
 pub fn db_enum_as_string(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
@@ -338,6 +372,15 @@ pub fn db_enum_as_string_with_model(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(DbValue, attributes(db_codec))]
+pub fn db_value(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).unwrap();
+    match crate::db_value::generate(&ast) {
+        Ok(result) => result,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_derive(DbJsonModel, attributes(enum_case, default_if_null,))]
 pub fn db_json_model(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
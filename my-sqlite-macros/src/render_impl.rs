@@ -22,8 +22,8 @@ pub fn implement_select_value_provider(
 
 pub fn impl_from_db_row(
     type_name: &TypeName,
-    fn_from_db_row: impl Fn() -> proc_macro2::TokenStream,
-    fn_from_db_row_opt: impl Fn() -> proc_macro2::TokenStream,
+    fn_try_from_db_row: impl Fn() -> proc_macro2::TokenStream,
+    fn_try_from_db_row_opt: impl Fn() -> proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let full_name_ident = type_name.to_token_stream();
 
@@ -31,17 +31,17 @@ pub fn impl_from_db_row(
 
     let trait_name = quote::quote!(my_sqlite::sql_select::FromDbRow<'s, #full_name_ident>);
 
-    let fn_from_db_row = fn_from_db_row();
-    let fn_from_db_row_opt = fn_from_db_row_opt();
+    let fn_try_from_db_row = fn_try_from_db_row();
+    let fn_try_from_db_row_opt = fn_try_from_db_row_opt();
 
     render_implement_trait(type_name, trait_name, || {
         quote::quote! {
-            fn from_db_row(row: &'s my_sqlite::DbRow, name: &str, metadata: &Option<my_sqlite::SqlValueMetadata>) -> #name_no_generics_ident {
-                #fn_from_db_row
+            fn try_from_db_row(row: &'s my_sqlite::DbRow, column_name: my_sqlite::sql_select::DbColumnName, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<#name_no_generics_ident, my_sqlite::DecodeError> {
+                #fn_try_from_db_row
             }
 
-            fn from_db_row_opt(row: &'s my_sqlite::DbRow, name: &str, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Option<#name_no_generics_ident> {
-                #fn_from_db_row_opt
+            fn try_from_db_row_opt(row: &'s my_sqlite::DbRow, column_name: my_sqlite::sql_select::DbColumnName, metadata: &Option<my_sqlite::SqlValueMetadata>) -> Result<Option<#name_no_generics_ident>, my_sqlite::DecodeError> {
+                #fn_try_from_db_row_opt
             }
         }
     })
@@ -116,6 +116,28 @@ pub fn impl_sql_update_model(
     )
 }
 
+pub fn impl_sql_having_model(
+    type_name: &TypeName,
+    fn_fill_having_component: proc_macro2::TokenStream,
+    fn_has_conditions: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    render_implement_trait(
+        type_name,
+        quote::quote!(my_sqlite::sql_having::SqlHavingModel),
+        || {
+            quote::quote! {
+                fn fill_having_component(&self, sql: &mut String, params: &mut my_sqlite::sql::SqlValues){
+                    #fn_fill_having_component
+                }
+
+                fn has_conditions(&self) -> bool{
+                    #fn_has_conditions
+                }
+            }
+        },
+    )
+}
+
 pub fn impl_sql_where_model(
     type_name: &TypeName,
     fn_fill_where_component: proc_macro2::TokenStream,
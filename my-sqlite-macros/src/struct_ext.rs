@@ -7,6 +7,8 @@ pub struct DbColumnName<'s> {
     pub attr: Option<DbColumnNameAttribute<'s>>,
     pub property_name: &'s str,
     pub force_cast_db_type: bool,
+    pub is_json: bool,
+    pub has_codec: bool,
     pub ty: &'s PropertyType<'s>,
 }
 
@@ -46,7 +48,11 @@ impl<'s> DbColumnName<'s> {
             return attr.name.into();
         }
 
-        if crate::utils::is_type_transformed(self.ty) {
+        if self.has_codec {
+            return self.property_name.into();
+        }
+
+        if self.is_json || crate::utils::is_type_transformed(self.ty) {
             return format!("{}.transformed", self.property_name).into();
         }
 
@@ -93,6 +99,60 @@ pub trait StructPropertyExt<'s> {
 
     fn get_force_cast_db_type(&self) -> bool;
 
+    fn is_json(&self) -> bool;
+
+    fn get_db_codec(&self) -> Result<Option<syn::Path>, syn::Error>;
+
+    fn get_value_type_token(&self) -> proc_macro2::TokenStream {
+        let ty = if let PropertyType::OptionOf(ty) = self.get_ty() {
+            ty.as_ref()
+        } else {
+            self.get_ty()
+        };
+
+        let ty_token = ty.get_token_stream_with_generics();
+
+        if self.is_json() {
+            quote::quote!(my_sqlite::JsonColumn::<#ty_token>)
+        } else {
+            ty_token
+        }
+    }
+
+    fn wrap_value_ref(
+        &self,
+        value: proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        if let Some(codec) = self.get_db_codec()? {
+            return Ok(quote::quote!(&#codec::encode(#value)));
+        }
+
+        if self.is_json() {
+            Ok(quote::quote!(my_sqlite::JsonColumnRef(#value)))
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn wrap_update_value(
+        &self,
+        value: proc_macro2::TokenStream,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
+        if let Some(codec) = self.get_db_codec()? {
+            return Ok(quote::quote! {
+                my_sqlite::sql_update::SqlUpdateValueHolder::Owned(Box::new(#codec::encode(#value)))
+            });
+        }
+
+        if self.is_json() {
+            return Ok(quote::quote! {
+                my_sqlite::sql_update::SqlUpdateValueHolder::Json(#value)
+            });
+        }
+
+        Ok(quote::quote!(my_sqlite::sql_update::SqlUpdateValueHolder::Borrowed(#value)))
+    }
+
     fn fill_attributes(
         &self,
         fields: &mut Vec<TokenStream>,
@@ -115,18 +175,20 @@ pub trait StructPropertyExt<'s> {
         let ignore_if_none = self.has_ignore_if_none_attr();
 
         let result = if is_update {
+            let value = self.wrap_update_value(quote::quote!(&self.#name))?;
             quote::quote! {
                 my_sqlite::sql_update::SqlUpdateModelValue{
-                    value: Some(&self.#name),
+                    value: Some(#value),
                     ignore_if_none: #ignore_if_none,
                     metadata: #metadata
                 }
             }
             .into()
         } else {
+            let value = self.wrap_value_ref(quote::quote!(&self.#name))?;
             quote::quote! {
                 my_sqlite::SqlWhereValueWrapper::Value {
-                    value: &self.#name,
+                    value: #value,
                     metadata: #metadata
                 }
             }
@@ -160,18 +222,20 @@ pub trait StructPropertyExt<'s> {
 
         let result = if is_update {
             let ignore_if_none = self.has_ignore_if_none_attr();
+            let value = self.wrap_update_value(quote::quote!(value))?;
 
             quote::quote! {
                if let Some(value) = &self.#prop_name{
-                  my_sqlite::sql_update::SqlUpdateModelValue {value: Some(value), ignore_if_none:#ignore_if_none, metadata: #metadata}
+                  my_sqlite::sql_update::SqlUpdateModelValue {value: Some(#value), ignore_if_none:#ignore_if_none, metadata: #metadata}
                }else{
                 my_sqlite::sql_update::SqlUpdateModelValue {value: None, ignore_if_none:#ignore_if_none, metadata: #metadata}
                }
             }
         } else {
+            let value = self.wrap_value_ref(quote::quote!(value))?;
             quote::quote! {
                if let Some(value) = &self.#prop_name{
-                  my_sqlite::SqlWhereValueWrapper::Value {value, metadata: #metadata}
+                  my_sqlite::SqlWhereValueWrapper::Value {value: #value, metadata: #metadata}
                }else{
                     #else_case
                }
@@ -191,6 +255,19 @@ impl<'s> StructPropertyExt<'s> for StructProperty<'s> {
         self.get_field_name_ident()
     }
 
+    fn is_json(&self) -> bool {
+        self.attrs.has_attr(JsonAttribute::NAME)
+    }
+
+    fn get_db_codec(&self) -> Result<Option<syn::Path>, syn::Error> {
+        let attr: Option<DbCodecAttribute> = self.try_get_attribute()?;
+
+        match attr {
+            Some(attr) => Ok(Some(attr.get_codec_path()?)),
+            None => Ok(None),
+        }
+    }
+
     fn get_ty(&self) -> &PropertyType {
         &self.ty
     }
@@ -207,6 +284,8 @@ impl<'s> StructPropertyExt<'s> for StructProperty<'s> {
             attr,
             property_name: &self.name,
             force_cast_db_type,
+            is_json: self.is_json(),
+            has_codec: self.attrs.has_attr(DbCodecAttribute::NAME),
             ty: &self.ty,
         };
 
@@ -246,30 +325,129 @@ impl<'s> StructPropertyExt<'s> for StructProperty<'s> {
     fn get_field_metadata(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
         let sql_type: Option<SqlTypeAttribute> = self.try_get_attribute()?;
         let operator: Option<WhereOperatorAttribute> = self.try_get_attribute()?;
-        if sql_type.is_none() && operator.is_none() {
+        let separator: Option<SeparatorAttribute> = self.try_get_attribute()?;
+        let json_object_key: Option<JsonObjectKeyAttribute> = self.try_get_attribute()?;
+        let time_bucket: Option<TimeBucketAttribute> = self.try_get_attribute()?;
+        let window: Option<WindowAttribute> = self.try_get_attribute()?;
+
+        let ty = self.ty.get_token_stream().to_string();
+
+        if ty.contains("GroupByJsonObject") && json_object_key.is_none() {
+            return Err(syn::Error::new_spanned(
+                self.field,
+                "GroupByJsonObject requires json_object_key attribute",
+            ));
+        }
+
+        if let Some(time_bucket) = &time_bucket {
+            if !time_bucket.is_valid() {
+                return Err(syn::Error::new_spanned(
+                    self.field,
+                    format!("Invalid time_bucket: {}", time_bucket.value),
+                ));
+            }
+        } else if ty.contains("GroupByTimeBucket") {
+            return Err(syn::Error::new_spanned(
+                self.field,
+                "GroupByTimeBucket requires time_bucket attribute",
+            ));
+        }
+
+        if self.get_db_codec()?.is_none() {
+            if ty.contains("DateTimeAsMicroseconds") || ty.contains("GroupByTimeBucket") {
+                match &sql_type {
+                    Some(sql_type) if sql_type.name.is_date_time_storage() => {}
+                    Some(sql_type) => {
+                        return Err(syn::Error::new_spanned(
+                            self.field,
+                            format!(
+                                "sql_type(\"{}\") is not a date time storage",
+                                sql_type.name.as_str()
+                            ),
+                        ));
+                    }
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            self.field,
+                            "DateTimeAsMicroseconds requires sql_type attribute",
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let Some(operator) = &operator {
+            let is_range = matches!(
+                operator.op,
+                WhereOperator::GreaterThan
+                    | WhereOperator::LessThan
+                    | WhereOperator::GreaterOrEqual
+                    | WhereOperator::LessOrEqual
+            );
+
+            let is_real = matches!(
+                sql_type.as_ref().map(|sql_type| &sql_type.name),
+                Some(SqlType::Real)
+            );
+
+            let is_decimal = ty
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|segment| segment == "Decimal");
+
+            if is_range && is_decimal && !is_real {
+                return Err(syn::Error::new_spanned(
+                    self.field,
+                    format!(
+                        "Operator {} is not supported for Decimal stored as text. Use #[sql_type(\"real\")]",
+                        operator.op.get_metadata_operator()
+                    ),
+                ));
+            }
+        }
+
+        if sql_type.is_none()
+            && operator.is_none()
+            && separator.is_none()
+            && json_object_key.is_none()
+            && time_bucket.is_none()
+            && window.is_none()
+        {
             return Ok(quote::quote!(None));
         }
 
-        let sql_type = if let Some(sql_type) = sql_type {
+        let mut metadata = quote::quote!(my_sqlite::SqlValueMetadata::default());
+
+        if let Some(sql_type) = sql_type {
             let sql_type = sql_type.name.as_str();
-            quote::quote!(Some(#sql_type))
-        } else {
-            quote::quote!(None)
-        };
+            metadata = quote::quote!(#metadata.with_sql_type(#sql_type));
+        }
 
-        let operator = if let Some(operator) = operator {
+        if let Some(operator) = operator {
             let operator = operator.op.get_metadata_operator();
-            quote::quote!(Some(#operator))
-        } else {
-            quote::quote!(None)
-        };
+            metadata = quote::quote!(#metadata.with_operator(#operator));
+        }
+
+        if let Some(separator) = separator {
+            let separator = separator.value;
+            metadata = quote::quote!(#metadata.with_separator(#separator));
+        }
 
-        Ok(quote::quote! {
-            Some(my_sqlite::SqlValueMetadata{
-                sql_type: #sql_type,
-                operator: #operator
-            })
-        })
+        if let Some(json_object_key) = json_object_key {
+            let json_object_key = json_object_key.column_name;
+            metadata = quote::quote!(#metadata.with_json_object_key(#json_object_key));
+        }
+
+        if let Some(time_bucket) = time_bucket {
+            let time_bucket = time_bucket.value;
+            metadata = quote::quote!(#metadata.with_time_bucket(#time_bucket));
+        }
+
+        if let Some(window) = window {
+            let window = window.value;
+            metadata = quote::quote!(#metadata.with_window(#window));
+        }
+
+        Ok(quote::quote!(Some(#metadata)))
     }
 
     fn fill_attributes(
@@ -291,19 +469,27 @@ impl<'s> StructPropertyExt<'s> for StructProperty<'s> {
             fields.push(sql_type.generate_attribute());
         }
 
+        if self.is_json() {
+            fields.push(quote::quote!(#[json]));
+        }
+
+        let db_codec: Option<DbCodecAttribute> = self.try_get_attribute()?;
+
+        if let Some(db_codec) = db_codec {
+            fields.push(db_codec.generate_attribute());
+        }
+
         Ok(())
     }
 
     fn get_sql_type_as_token_stream(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
-        let ty = if let PropertyType::OptionOf(ty) = &self.ty {
-            ty.as_ref()
-        } else {
-            &self.ty
-        };
+        let meta_data = self.get_field_metadata()?;
 
-        let ty_token = ty.get_token_stream_with_generics();
+        if let Some(codec) = self.get_db_codec()? {
+            return Ok(quote::quote!(my_sqlite::codec_get_sql_type(#codec::encode, #meta_data)));
+        }
 
-        let meta_data = self.get_field_metadata()?;
+        let ty_token = self.get_value_type_token();
 
         Ok(quote::quote! {#ty_token:: get_sql_type(#meta_data)})
     }
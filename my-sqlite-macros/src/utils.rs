@@ -4,6 +4,7 @@ pub fn is_type_transformed(tp: &types_reader::PropertyType) -> bool {
         types_reader::PropertyType::VecOf(_) => true,
         types_reader::PropertyType::HashMap(_, _) => true,
         types_reader::PropertyType::Struct(name, _) => return name == "BTreeMap",
+        types_reader::PropertyType::OptionOf(sub_type) => is_type_transformed(sub_type),
         _ => false,
     }
 }
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use types_reader::{StructProperty, TypeName};
 
-use crate::{struct_ext::{StructPropertyExt, DbColumnName}, struct_schema::StructSchema};
+use crate::{attributes::{WhereOperator, WhereOperatorAttribute}, struct_ext::{StructPropertyExt, DbColumnName}, struct_schema::StructSchema};
 
 pub struct WhereFields<'s> {
     pub limit: Option<&'s StructProperty<'s>>,
@@ -149,6 +149,13 @@ impl<'s> WhereFields<'s> {
     }
 
     pub fn fn_fill_where_content(&self) -> Result<proc_macro2::TokenStream, syn::Error> {
+        self.fn_fill_condition_content(|prop| Ok(prop.get_db_column_name()?.as_str().to_string()))
+    }
+
+    pub fn fn_fill_condition_content(
+        &self,
+        get_column_name: impl Fn(&StructProperty<'s>) -> Result<String, syn::Error>,
+    ) -> Result<proc_macro2::TokenStream, syn::Error> {
         let mut lines = Vec::new();
 
         lines.push(quote::quote! {
@@ -157,18 +164,32 @@ impl<'s> WhereFields<'s> {
 
         for prop in &self.where_fields {
             let prop_name_ident = prop.get_field_name_ident();
-            let db_column_name = prop.get_db_column_name()?;
+            let db_column_name = get_column_name(prop)?;
             let metadata = prop.get_field_metadata()?;
 
             let ignore_if_none = prop.has_ignore_if_none_attr();
 
-            let where_condition = render_full_where_condition(&db_column_name, None);
+            let where_condition = render_full_condition(db_column_name.as_str(), None);
+
+            let operator: Option<WhereOperatorAttribute> = prop.try_get_attribute()?;
+
+            let is_json_contains = match &operator {
+                Some(operator) => matches!(operator.op, WhereOperator::JsonContains),
+                None => false,
+            };
+
+            let fill_option_value = if is_json_contains {
+                quote::quote!(my_sqlite::fill_json_array_contains(value, #where_condition, sql, params, &#metadata))
+            } else {
+                let value = prop.wrap_value_ref(quote::quote!(value))?;
+                quote::quote!((#value).fill_where_value(#where_condition, sql, params, &#metadata))
+            };
 
             if prop.ty.is_option() {
                 if ignore_if_none {
                     lines.push(quote::quote! {
                         if let Some(value) = &self.#prop_name_ident{
-                            if value.fill_where_value(#where_condition, sql, params, &#metadata){
+                            if #fill_option_value{
                                 condition_no+=1;
                             }
 
@@ -178,7 +199,7 @@ impl<'s> WhereFields<'s> {
                     let db_column_name = db_column_name.as_str();
                     lines.push(quote::quote! {
                         if let Some(value) = &self.#prop_name_ident{
-                            if value.fill_where_value(#where_condition, sql, params, &#metadata){
+                            if #fill_option_value{
                                 condition_no+=1;
                             }
                         }
@@ -192,9 +213,16 @@ impl<'s> WhereFields<'s> {
                         condition_no+=1;
                     });
                 }
+            } else if is_json_contains {
+                lines.push(quote::quote! {
+                    if my_sqlite::fill_json_array_contains(&self.#prop_name_ident, #where_condition, sql, params, &#metadata){
+                        condition_no+=1;
+                    }
+                });
             } else {
+                let value = prop.wrap_value_ref(quote::quote!(&self.#prop_name_ident))?;
                 lines.push(quote::quote! {
-                    if self.#prop_name_ident.fill_where_value(#where_condition, sql, params, &#metadata){
+                    if (#value).fill_where_value(#where_condition, sql, params, &#metadata){
                         condition_no+=1;
                     }
                     
@@ -213,6 +241,13 @@ impl<'s> WhereFields<'s> {
 pub fn render_full_where_condition(
     db_column_name: &DbColumnName,
     json_column_name: Option<&str>,
+) -> proc_macro2::TokenStream {
+    render_full_condition(db_column_name.as_str(), json_column_name)
+}
+
+pub fn render_full_condition(
+    db_column_name: &str,
+    json_column_name: Option<&str>,
 ) -> proc_macro2::TokenStream {
     let json_column_name = if let Some(json_column_name) = json_column_name {
         let json_column_name = proc_macro2::TokenStream::from_str(json_column_name).unwrap();
@@ -220,8 +255,7 @@ pub fn render_full_where_condition(
     } else {
         quote::quote!(None)
     };
-    
-    let db_column_name = db_column_name.as_str();
+
     quote::quote! {
         Some(my_sqlite::RenderFullWhereCondition{
             column_name: #db_column_name,
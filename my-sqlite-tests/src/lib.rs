@@ -1,5 +1,51 @@
+mod test_bulk_insert;
+mod test_count;
 mod test_create_select_with_pk_single_column;
 mod test_create_select_with_pk_single_two_columns;
+mod test_date_time_storage;
+mod test_db_value;
+mod test_decode_errors;
+mod test_errors;
+mod test_external_types;
+mod test_group_by_aggregates;
+mod test_having;
+mod test_json_array_contains;
+mod test_json_column;
+mod test_json_update;
 mod test_log_dto;
+mod test_logger;
+mod test_metrics;
+mod test_nullable_round_trip;
+mod test_query_chunks;
+mod test_query_page;
+mod test_query_page_by_cursor;
+mod test_query_stream;
+mod test_query_timeout;
 mod test_raw_sql;
+mod test_scalar_aggregates;
+mod test_slow_query;
+mod test_statement_cache;
+mod test_time_bucket;
+mod test_window_functions;
 mod test_with_timestamp;
+
+#[cfg(test)]
+async fn create_connection_with_entities<
+    TEntity: my_sqlite::table_schema::TableSchemaProvider + my_sqlite::sql_insert::SqlInsertModel,
+>(
+    table_name: &str,
+    entities: &[TEntity],
+) -> my_sqlite::SqlLiteConnection {
+    let connection = my_sqlite::SqlLiteConnectionBuilder::new(":memory:")
+        .create_table_if_no_exists::<TEntity>(table_name)
+        .build()
+        .await
+        .unwrap();
+
+    connection
+        .bulk_insert_db_entities(entities, table_name)
+        .await
+        .unwrap();
+
+    connection
+}
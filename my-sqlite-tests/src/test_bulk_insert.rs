@@ -0,0 +1,76 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, UpdateDbEntity, SelectDbEntity, Debug)]
+pub struct QuoteDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub bid: f64,
+    pub ask: f32,
+    pub volume: u64,
+    pub instrument: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::sql_where::NoneWhereModel;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "quotes";
+
+    const ROWS_COUNT: i64 = 20_000;
+
+    fn create_entities(volume: u64) -> Vec<QuoteDto> {
+        (0..ROWS_COUNT)
+            .map(|id| QuoteDto {
+                id,
+                bid: id as f64 + 0.5,
+                ask: 1.1,
+                volume,
+                instrument: format!("instrument-{}", id),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_bulk_insert_above_variable_limit() {
+        let connection =
+            crate::create_connection_with_entities(TABLE_NAME, &create_entities(1)).await;
+
+        let count = connection
+            .get_count::<NoneWhereModel, usize>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        assert_eq!(ROWS_COUNT as usize, count);
+
+        connection
+            .bulk_insert_or_update(&create_entities(2), TABLE_NAME)
+            .await
+            .unwrap();
+
+        let result: Option<QuoteDto> = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: ROWS_COUNT - 1 }))
+            .await
+            .unwrap();
+
+        let result = result.unwrap();
+
+        assert_eq!(2, result.volume);
+        assert_eq!(1.1, result.ask);
+        assert_eq!((ROWS_COUNT - 1) as f64 + 0.5, result.bid);
+        assert_eq!(format!("instrument-{}", ROWS_COUNT - 1), result.instrument);
+
+        let count = connection
+            .get_count::<NoneWhereModel, usize>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        assert_eq!(ROWS_COUNT as usize, count);
+    }
+}
@@ -0,0 +1,116 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct OrderDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub client_id: String,
+    pub status: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByStatusModel {
+    pub status: String,
+    #[limit]
+    pub limit: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::sql_where::NoneWhereModel;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "orders";
+
+    fn get_entities() -> Vec<OrderDto> {
+        let entities = vec![
+            ("client-1", "new"),
+            ("client-1", "done"),
+            ("client-2", "done"),
+            ("client-3", "done"),
+            ("client-3", "new"),
+        ];
+
+        entities
+            .into_iter()
+            .enumerate()
+            .map(|(id, (client_id, status))| OrderDto {
+                id: id as i64,
+                client_id: client_id.to_string(),
+                status: status.to_string(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_count_ignores_limit() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let total: u64 = connection
+            .get_count::<NoneWhereModel, _>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        assert_eq!(total, 5);
+
+        let where_model = WhereByStatusModel {
+            status: "done".to_string(),
+            limit: 1,
+        };
+
+        let done: usize = connection
+            .get_count(TABLE_NAME, Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(done, 3);
+    }
+
+    #[tokio::test]
+    async fn test_count_distinct_and_grouped() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let clients: i64 = connection
+            .count_distinct::<NoneWhereModel, _>(TABLE_NAME, "client_id", None)
+            .await
+            .unwrap();
+
+        assert_eq!(clients, 3);
+
+        let by_status: Vec<(String, u64)> = connection
+            .count_grouped_by::<NoneWhereModel, _>(TABLE_NAME, "status", None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            by_status,
+            vec![("done".to_string(), 3), ("new".to_string(), 2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exists() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereByStatusModel {
+            status: "new".to_string(),
+            limit: 1,
+        };
+
+        assert!(connection
+            .exists(TABLE_NAME, Some(&where_model))
+            .await
+            .unwrap());
+
+        let where_model = WhereByStatusModel {
+            status: "cancelled".to_string(),
+            limit: 1,
+        };
+
+        assert!(!connection
+            .exists(TABLE_NAME, Some(&where_model))
+            .await
+            .unwrap());
+    }
+}
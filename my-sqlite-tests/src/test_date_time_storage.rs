@@ -0,0 +1,114 @@
+use my_sqlite::macros::*;
+use types_reader::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct MomentsDto {
+    #[primary_key(0)]
+    pub id: i32,
+    #[sql_type("unix_microseconds")]
+    pub as_microseconds: DateTimeAsMicroseconds,
+    #[sql_type("unix_milliseconds")]
+    pub as_milliseconds: DateTimeAsMicroseconds,
+    #[sql_type("unix_seconds")]
+    pub as_seconds: DateTimeAsMicroseconds,
+    #[sql_type("timestamp")]
+    pub as_timestamp: DateTimeAsMicroseconds,
+    #[sql_type("timestamp_milliseconds")]
+    pub as_timestamp_milliseconds: DateTimeAsMicroseconds,
+    #[sql_type("timestamp_seconds")]
+    pub as_timestamp_seconds: DateTimeAsMicroseconds,
+    #[sql_type("julianday")]
+    pub as_julian_day: DateTimeAsMicroseconds,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByTimestampModel {
+    #[sql_type("timestamp")]
+    #[db_column_name("as_timestamp")]
+    #[operator(">=")]
+    pub from_date: DateTimeAsMicroseconds,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByMillisecondsModel {
+    #[sql_type("unix_milliseconds")]
+    #[db_column_name("as_milliseconds")]
+    pub moment: DateTimeAsMicroseconds,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "moments";
+
+    #[tokio::test]
+    async fn test_each_storage_round_trips() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<MomentsDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let moment = DateTimeAsMicroseconds::from_str("2021-01-02T03:04:05.123456").unwrap();
+
+        let dto = MomentsDto {
+            id: 1,
+            as_microseconds: moment,
+            as_milliseconds: moment,
+            as_seconds: moment,
+            as_timestamp: moment,
+            as_timestamp_milliseconds: moment,
+            as_timestamp_seconds: moment,
+            as_julian_day: moment,
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        let result: Vec<MomentsDto> = connection
+            .query_rows(
+                TABLE_NAME,
+                Some(&WhereByTimestampModel { from_date: moment }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+
+        let result: Vec<MomentsDto> = connection
+            .query_rows(
+                TABLE_NAME,
+                Some(&WhereByMillisecondsModel { moment: moment }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+
+        let result = result.into_iter().next().unwrap();
+
+        let unix_microseconds = moment.unix_microseconds;
+
+        assert_eq!(unix_microseconds, result.as_microseconds.unix_microseconds);
+        assert_eq!(
+            unix_microseconds - 456,
+            result.as_milliseconds.unix_microseconds
+        );
+        assert_eq!(
+            unix_microseconds - 123_456,
+            result.as_seconds.unix_microseconds
+        );
+        assert_eq!(unix_microseconds, result.as_timestamp.unix_microseconds);
+        assert_eq!(
+            unix_microseconds - 456,
+            result.as_timestamp_milliseconds.unix_microseconds
+        );
+        assert_eq!(
+            unix_microseconds - 123_456,
+            result.as_timestamp_seconds.unix_microseconds
+        );
+        assert!((unix_microseconds - result.as_julian_day.unix_microseconds).abs() < 1_000);
+    }
+}
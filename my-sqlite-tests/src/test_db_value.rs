@@ -0,0 +1,258 @@
+use my_sqlite::macros::*;
+
+#[derive(DbValue, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserId(i64);
+
+#[derive(DbValue, Debug, Clone, PartialEq, Eq)]
+pub struct Email(String);
+
+#[derive(DbValue, Debug, Clone, Copy, PartialEq)]
+pub struct Percent(#[db_codec(with = "basis_points_codec")] f64);
+
+#[derive(DbValue, Debug, Clone, PartialEq, Eq)]
+#[db_codec(with = "tags_codec")]
+pub struct Tags {
+    pub items: Vec<String>,
+}
+
+mod basis_points_codec {
+    pub fn encode(value: &f64) -> i64 {
+        (value * 100.0).round() as i64
+    }
+
+    pub fn decode(value: i64) -> Result<f64, String> {
+        Ok(value as f64 / 100.0)
+    }
+}
+
+mod tags_codec {
+    use super::Tags;
+
+    pub fn encode(value: &Tags) -> String {
+        value.items.join(";")
+    }
+
+    pub fn decode(value: String) -> Result<Tags, String> {
+        if value.is_empty() {
+            return Err("Tags can not be empty".to_string());
+        }
+
+        Ok(Tags {
+            items: value.split(';').map(|itm| itm.to_string()).collect(),
+        })
+    }
+}
+
+mod labels_codec {
+    pub fn encode(value: &Vec<String>) -> String {
+        value.join(",")
+    }
+
+    pub fn decode(value: String) -> Result<Vec<String>, String> {
+        Ok(value.split(',').map(|itm| itm.to_string()).collect())
+    }
+}
+
+#[derive(TableSchema, InsertDbEntity, UpdateDbEntity, SelectDbEntity, Debug)]
+pub struct ArticleDto {
+    #[primary_key(0)]
+    pub id: i64,
+    #[db_codec(with = "labels_codec")]
+    pub labels: Vec<String>,
+    #[db_codec(with = "basis_points_codec")]
+    pub rating: Option<f64>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByLabelsModel {
+    #[db_codec(with = "labels_codec")]
+    pub labels: Vec<String>,
+}
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct UserDto {
+    #[primary_key(0)]
+    pub id: UserId,
+    pub email: Email,
+    pub tags: Tags,
+    pub discount: Percent,
+    pub parent_id: Option<UserId>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: UserId,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByEmailModel {
+    pub email: Email,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByDiscountModel {
+    pub discount: Percent,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "users";
+
+    #[tokio::test]
+    async fn test_newtype_and_codec_round_trip() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<UserDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let dto = UserDto {
+            id: UserId(15),
+            email: Email("Test@Email.com".to_string()),
+            tags: Tags {
+                items: vec!["a".to_string(), "b".to_string()],
+            },
+            discount: Percent(12.5),
+            parent_id: None,
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        let result: UserDto = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: UserId(15) }))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(UserId(15), result.id);
+        assert_eq!(dto.email, result.email);
+        assert_eq!(dto.tags, result.tags);
+        assert_eq!(dto.discount, result.discount);
+        assert!(result.parent_id.is_none());
+
+        let result: Option<UserDto> = connection
+            .query_single_row(
+                TABLE_NAME,
+                Some(&WhereByEmailModel {
+                    email: Email("Test@Email.com".to_string()),
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(UserId(15), result.unwrap().id);
+
+        let result: Option<UserDto> = connection
+            .query_single_row(
+                TABLE_NAME,
+                Some(&WhereByDiscountModel {
+                    discount: Percent(12.5),
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(UserId(15), result.unwrap().id);
+    }
+
+    #[tokio::test]
+    async fn test_codec_decode_error_is_a_decode_error() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<UserDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let dto = UserDto {
+            id: UserId(16),
+            email: Email("empty@email.com".to_string()),
+            tags: Tags { items: vec![] },
+            discount: Percent(0.0),
+            parent_id: None,
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        let result: Result<Option<UserDto>, my_sqlite::SqlLiteError> = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: UserId(16) }))
+            .await;
+
+        match result {
+            Err(my_sqlite::SqlLiteError::Decode { column, .. }) => assert_eq!("tags", column),
+            _ => panic!("Expected decode error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_codec_on_entity_fields() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<ArticleDto>("articles")
+            .build()
+            .await
+            .unwrap();
+
+        let dto = ArticleDto {
+            id: 1,
+            labels: vec!["rust".to_string(), "sqlite".to_string()],
+            rating: Some(4.25),
+        };
+
+        connection.insert_db_entity(&dto, "articles").await.unwrap();
+
+        connection
+            .insert_db_entity(
+                &ArticleDto {
+                    id: 2,
+                    labels: vec!["go".to_string()],
+                    rating: None,
+                },
+                "articles",
+            )
+            .await
+            .unwrap();
+
+        let result: Vec<ArticleDto> = connection
+            .query_rows(
+                "articles",
+                Some(&WhereByLabelsModel {
+                    labels: vec!["rust".to_string(), "sqlite".to_string()],
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(dto.labels, result[0].labels);
+        assert_eq!(Some(4.25), result[0].rating);
+
+        connection
+            .update_db_entity(
+                &ArticleDto {
+                    id: 2,
+                    labels: vec!["go".to_string(), "tokio".to_string()],
+                    rating: Some(3.5),
+                },
+                "articles",
+            )
+            .await
+            .unwrap();
+
+        let result: Vec<ArticleDto> = connection
+            .query_rows(
+                "articles",
+                Some(&WhereByLabelsModel {
+                    labels: vec!["go".to_string(), "tokio".to_string()],
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(2, result[0].id);
+        assert_eq!(Some(3.5), result[0].rating);
+    }
+}
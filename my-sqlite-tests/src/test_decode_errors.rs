@@ -0,0 +1,116 @@
+use my_sqlite::macros::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeviceSettings {
+    pub volume: u8,
+}
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct DeviceDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub level: u8,
+    #[json]
+    pub settings: DeviceSettings,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{DecodeErrorSource, SqlLiteConnection, SqlLiteError};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "devices";
+
+    async fn create_connection() -> SqlLiteConnection {
+        let entities: Vec<_> = (1..=2)
+            .map(|id| DeviceDto {
+                id,
+                level: 5,
+                settings: DeviceSettings { volume: 10 },
+            })
+            .collect();
+
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &entities).await;
+
+        connection
+            .client
+            .conn(|conn| {
+                conn.execute(
+                    "INSERT INTO devices (id, level, settings) VALUES (3, 5, '{not json')",
+                    [],
+                )?;
+                conn.execute(
+                    "INSERT INTO devices (id, level, settings) VALUES (4, 300, '{\"volume\":1}')",
+                    [],
+                )
+            })
+            .await
+            .unwrap();
+
+        connection
+    }
+
+    #[tokio::test]
+    async fn test_query_rows_returns_decode_error() {
+        let connection = create_connection().await;
+
+        let result: Result<Vec<DeviceDto>, SqlLiteError> = connection
+            .query_rows(TABLE_NAME, None::<&WhereByIdModel>)
+            .await;
+
+        match result {
+            Err(SqlLiteError::Decode {
+                column,
+                row,
+                source,
+                ..
+            }) => {
+                assert_eq!("settings", column);
+                assert_eq!(2, row);
+                assert!(matches!(source, DecodeErrorSource::Json(_)));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        let result: Option<DeviceDto> = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: 1 }))
+            .await
+            .unwrap();
+
+        assert_eq!(10, result.unwrap().settings.volume);
+    }
+
+    #[tokio::test]
+    async fn test_skipping_bad_rows() {
+        let connection = create_connection().await;
+
+        let result = connection
+            .query_rows_skipping_bad_rows::<DeviceDto, WhereByIdModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        assert_eq!(2, result.items.len());
+        assert_eq!(2, result.errors.len());
+
+        match &result.errors[1] {
+            SqlLiteError::Decode {
+                column,
+                row,
+                source,
+                ..
+            } => {
+                assert_eq!("level", column);
+                assert_eq!(3, *row);
+                assert!(matches!(source, DecodeErrorSource::InvalidValue(_)));
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, UpdateDbEntity, SelectDbEntity, Debug)]
+pub struct UserDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{SqlLiteConnection, SqlLiteConnectionBuilder, SqlLiteError};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "users";
+
+    async fn create_connection(redact_sql_in_errors: bool) -> SqlLiteConnection {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<UserDto>(TABLE_NAME)
+            .redact_sql_in_errors(redact_sql_in_errors)
+            .build()
+            .await
+            .unwrap();
+
+        let dto = UserDto {
+            id: 1,
+            name: "Alice".to_string(),
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        connection
+    }
+
+    #[tokio::test]
+    async fn test_unique_violation() {
+        let connection = create_connection(false).await;
+
+        let dto = UserDto {
+            id: 1,
+            name: "Bob".to_string(),
+        };
+
+        let err = connection
+            .insert_db_entity(&dto, TABLE_NAME)
+            .await
+            .unwrap_err();
+
+        match &err {
+            SqlLiteError::UniqueViolation { table, index, sql, .. } => {
+                assert_eq!("users", table);
+                assert_eq!("id", index);
+                assert!(sql.as_ref().unwrap().starts_with("INSERT INTO users"));
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+
+        assert!(err.to_string().starts_with("Unique constraint violation"));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_schema_mismatch_with_redacted_sql() {
+        let connection = create_connection(true).await;
+
+        let err = connection
+            .client
+            .conn(|conn| conn.execute("DELETE FROM missing_table WHERE name='Alice'", []))
+            .await
+            .map_err(SqlLiteError::from)
+            .unwrap_err()
+            .with_sql("DELETE FROM missing_table WHERE name='Alice'", true);
+
+        match err {
+            SqlLiteError::SchemaMismatch { sql, .. } => {
+                assert_eq!("DELETE FROM missing_table WHERE name=?", sql.unwrap());
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+
+        let err = connection
+            .query_rows::<UserDto, WhereByIdModel>("missing_table", None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SqlLiteError::SchemaMismatch { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_insert_and_delete_return_errors() {
+        let connection = create_connection(false).await;
+
+        let entities = vec![
+            UserDto {
+                id: 2,
+                name: "Bob".to_string(),
+            },
+            UserDto {
+                id: 1,
+                name: "Alice".to_string(),
+            },
+        ];
+
+        let err = connection
+            .bulk_insert_db_entities(&entities, TABLE_NAME)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SqlLiteError::UniqueViolation { .. }));
+
+        let err = connection
+            .bulk_insert_or_update(&entities, "missing_table")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SqlLiteError::SchemaMismatch { .. }));
+
+        let err = connection
+            .delete_db_entity("missing_table", &WhereByIdModel { id: 1 })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SqlLiteError::SchemaMismatch { .. }));
+    }
+}
@@ -0,0 +1,185 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use my_sqlite::macros::*;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct PaymentDto {
+    #[primary_key(0)]
+    #[sql_type("blob")]
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub amount: Decimal,
+    pub created: DateTime<Utc>,
+    #[sql_type("unix_milliseconds")]
+    pub processed: Option<DateTime<Utc>>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    #[sql_type("blob")]
+    pub id: Uuid,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByClientModel {
+    pub client_id: Uuid,
+    #[db_column_name("created")]
+    #[operator(">=")]
+    pub from_date: DateTime<Utc>,
+}
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct ShipmentDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub created: OffsetDateTime,
+    #[sql_type("unix_milliseconds")]
+    pub delivered: Option<OffsetDateTime>,
+    #[sql_type("real")]
+    pub weight: Decimal,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereCreatedFromModel {
+    #[db_column_name("created")]
+    #[operator(">=")]
+    pub from_date: OffsetDateTime,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereHeavierThanModel {
+    #[db_column_name("weight")]
+    #[operator(">")]
+    #[sql_type("real")]
+    pub weight: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "payments";
+
+    #[tokio::test]
+    async fn test_uuid_decimal_and_chrono_round_trip() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<PaymentDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let created = DateTime::<Utc>::from_timestamp_micros(1_609_556_645_123_456).unwrap();
+
+        let dto = PaymentDto {
+            id: Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+            client_id: Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap(),
+            amount: Decimal::from_str("12345.678901").unwrap(),
+            created,
+            processed: None,
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        let result: PaymentDto = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: dto.id }))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(dto.id, result.id);
+        assert_eq!(dto.client_id, result.client_id);
+        assert_eq!(dto.amount, result.amount);
+        assert_eq!(dto.created, result.created);
+        assert!(result.processed.is_none());
+
+        let result: Vec<PaymentDto> = connection
+            .query_rows(
+                TABLE_NAME,
+                Some(&WhereByClientModel {
+                    client_id: dto.client_id,
+                    from_date: created,
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+    }
+
+    #[tokio::test]
+    async fn test_time_round_trip_and_real_decimal_ranges() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<ShipmentDto>("shipments")
+            .build()
+            .await
+            .unwrap();
+
+        let created = OffsetDateTime::from_unix_timestamp_nanos(1_609_556_645_123_456_000).unwrap();
+        let delivered = OffsetDateTime::from_unix_timestamp_nanos(1_609_643_045_123_000_000).unwrap();
+
+        let entities = vec![
+            ShipmentDto {
+                id: 1,
+                created,
+                delivered: Some(delivered),
+                weight: Decimal::from_str("9").unwrap(),
+            },
+            ShipmentDto {
+                id: 2,
+                created: delivered,
+                delivered: None,
+                weight: Decimal::from_str("10.5").unwrap(),
+            },
+        ];
+
+        connection
+            .bulk_insert_db_entities(&entities, "shipments")
+            .await
+            .unwrap();
+
+        let result: Vec<ShipmentDto> = connection
+            .query_rows(
+                "shipments",
+                Some(&WhereCreatedFromModel { from_date: created }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(2, result.len());
+        assert_eq!(created, result[0].created);
+        assert_eq!(Some(delivered), result[0].delivered);
+        assert!(result[1].delivered.is_none());
+
+        let result: Vec<ShipmentDto> = connection
+            .query_rows(
+                "shipments",
+                Some(&WhereCreatedFromModel {
+                    from_date: delivered,
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(2, result[0].id);
+
+        let result: Vec<ShipmentDto> = connection
+            .query_rows(
+                "shipments",
+                Some(&WhereHeavierThanModel {
+                    weight: Decimal::from_str("9.5").unwrap(),
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(2, result[0].id);
+    }
+}
@@ -0,0 +1,125 @@
+use my_sqlite::{
+    macros::*, GroupByConcat, GroupByCountDistinct, GroupByJsonArray, GroupByJsonObject,
+    GroupByTotal,
+};
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct OrderDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub client_id: String,
+    pub currency: String,
+    pub merchant: String,
+    pub amount: f64,
+    pub quantity: i64,
+}
+
+#[derive(SelectDbEntity)]
+pub struct ClientOrdersDto {
+    #[group_by]
+    #[order_by]
+    pub client_id: String,
+    #[db_column_name("currency")]
+    #[separator("|")]
+    pub currencies: GroupByConcat,
+    #[db_column_name("amount")]
+    pub total_amount: GroupByTotal,
+    #[db_column_name("merchant")]
+    pub merchants_count: GroupByCountDistinct<i64>,
+    #[db_column_name("id")]
+    pub order_ids: GroupByJsonArray<i64>,
+    #[db_column_name("quantity")]
+    #[json_object_key("id")]
+    pub quantities: GroupByJsonObject<i64, i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{sql_where::NoneWhereModel, SqlLiteConnectionBuilder};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "orders";
+
+    #[tokio::test]
+    async fn test_group_by_aggregates() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<OrderDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let entities = vec![
+            OrderDto {
+                id: 1,
+                client_id: "client-1".to_string(),
+                currency: "USD".to_string(),
+                merchant: "shop-1".to_string(),
+                amount: 10.0,
+                quantity: 1,
+            },
+            OrderDto {
+                id: 2,
+                client_id: "client-1".to_string(),
+                currency: "EUR".to_string(),
+                merchant: "shop-2".to_string(),
+                amount: 2.5,
+                quantity: 5,
+            },
+            OrderDto {
+                id: 3,
+                client_id: "client-1".to_string(),
+                currency: "USD".to_string(),
+                merchant: "shop-1".to_string(),
+                amount: 1.5,
+                quantity: 2,
+            },
+            OrderDto {
+                id: 4,
+                client_id: "client-2".to_string(),
+                currency: "GBP".to_string(),
+                merchant: "shop-1".to_string(),
+                amount: 7.0,
+                quantity: 3,
+            },
+        ];
+
+        connection
+            .bulk_insert_db_entities(&entities, TABLE_NAME)
+            .await
+            .unwrap();
+
+        let result: Vec<ClientOrdersDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+
+        let client = &result[0];
+        assert_eq!(client.client_id, "client-1");
+
+        let mut currencies = client.currencies.get_items();
+        currencies.sort();
+        assert_eq!(currencies, vec!["EUR", "USD", "USD"]);
+        assert_eq!(client.currencies.get_separator(), "|");
+
+        assert_eq!(client.total_amount.get_value(), 14.0);
+        assert_eq!(client.merchants_count.get_value(), 2);
+
+        let mut order_ids = client.order_ids.get_value().to_vec();
+        order_ids.sort();
+        assert_eq!(order_ids, vec![1, 2, 3]);
+
+        assert_eq!(client.quantities.get_value().len(), 3);
+        assert_eq!(client.quantities.get_value().get(&2), Some(&5));
+
+        let client = &result[1];
+        assert_eq!(client.client_id, "client-2");
+        assert_eq!(client.currencies.get_value(), "GBP");
+        assert_eq!(client.total_amount.get_value(), 7.0);
+        assert_eq!(client.merchants_count.get_value(), 1);
+        assert_eq!(client.order_ids.get_value(), &[4]);
+        assert_eq!(client.quantities.get_value().get(&4), Some(&3));
+    }
+}
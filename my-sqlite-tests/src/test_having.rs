@@ -0,0 +1,104 @@
+use my_sqlite::{macros::*, GroupByCount};
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct LoginAttemptDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub account_id: String,
+    pub failed: bool,
+}
+
+#[derive(SelectDbEntity, Debug)]
+pub struct FailedLoginsDto {
+    #[group_by]
+    #[order_by]
+    pub account_id: String,
+    pub failed_count: GroupByCount<i64>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereFailedModel {
+    pub failed: bool,
+}
+
+#[derive(HavingDbModel)]
+pub struct HavingFailedCountModel {
+    #[aggregate("COUNT(*)")]
+    #[operator(">")]
+    pub failed_count: i64,
+}
+
+#[derive(HavingDbModel)]
+pub struct HavingOptionalModel {
+    #[aggregate("COUNT(*)")]
+    #[operator(">=")]
+    #[ignore_if_none]
+    pub failed_count: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE_NAME: &str = "login_attempts";
+
+    fn get_entities() -> Vec<LoginAttemptDto> {
+        let mut entities = Vec::new();
+
+        for (account_id, failed_count) in [("acc-1", 12), ("acc-2", 3), ("acc-3", 11)] {
+            for _ in 0..failed_count {
+                entities.push(LoginAttemptDto {
+                    id: entities.len() as i64,
+                    account_id: account_id.to_string(),
+                    failed: true,
+                });
+            }
+
+            entities.push(LoginAttemptDto {
+                id: entities.len() as i64,
+                account_id: account_id.to_string(),
+                failed: false,
+            });
+        }
+
+        entities
+    }
+
+    #[tokio::test]
+    async fn test_accounts_with_more_than_10_failed_logins() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereFailedModel { failed: true };
+        let having_model = HavingFailedCountModel { failed_count: 10 };
+
+        let result: Vec<FailedLoginsDto> = connection
+            .query_rows_with_having(TABLE_NAME, Some(&where_model), Some(&having_model))
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+
+        assert_eq!(result[0].account_id, "acc-1");
+        assert_eq!(result[0].failed_count.get_value(), 12);
+
+        assert_eq!(result[1].account_id, "acc-3");
+        assert_eq!(result[1].failed_count.get_value(), 11);
+    }
+
+    #[tokio::test]
+    async fn test_having_without_conditions() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereFailedModel { failed: true };
+        let having_model = HavingOptionalModel { failed_count: None };
+
+        let result: Vec<FailedLoginsDto> = connection
+            .query_rows_with_having(TABLE_NAME, Some(&where_model), Some(&having_model))
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1].account_id, "acc-2");
+        assert_eq!(result[1].failed_count.get_value(), 3);
+    }
+}
@@ -0,0 +1,93 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct ArticleDto {
+    #[primary_key(0)]
+    pub id: i32,
+    pub tags: Vec<String>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByTagModel {
+    #[db_column_name("tags")]
+    #[operator("json_contains")]
+    pub tag: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByAnyTagModel {
+    #[db_column_name("tags")]
+    #[operator("json_contains")]
+    #[ignore_if_none]
+    pub tags: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "articles";
+
+    #[tokio::test]
+    async fn test_json_array_contains() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<ArticleDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let to_insert = vec![
+            ArticleDto {
+                id: 1,
+                tags: vec!["rust".to_string(), "sqlite".to_string()],
+            },
+            ArticleDto {
+                id: 2,
+                tags: vec!["postgres".to_string()],
+            },
+            ArticleDto {
+                id: 3,
+                tags: vec![],
+            },
+        ];
+
+        connection
+            .bulk_insert_db_entities(&to_insert, TABLE_NAME)
+            .await
+            .unwrap();
+
+        let result: Vec<ArticleDto> = connection
+            .query_rows(
+                TABLE_NAME,
+                Some(&WhereByTagModel {
+                    tag: "sqlite".to_string(),
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(1, result[0].id);
+
+        let result: Vec<ArticleDto> = connection
+            .query_rows(
+                TABLE_NAME,
+                Some(&WhereByAnyTagModel {
+                    tags: Some(vec!["rust".to_string(), "postgres".to_string()]),
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(2, result.len());
+
+        let result: Vec<ArticleDto> = connection
+            .query_rows(TABLE_NAME, Some(&WhereByAnyTagModel { tags: Some(vec![]) }))
+            .await
+            .unwrap();
+
+        assert_eq!(0, result.len());
+    }
+}
@@ -0,0 +1,99 @@
+use my_sqlite::macros::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UserSettings {
+    pub theme: String,
+    pub notifications: bool,
+    pub page_size: u32,
+}
+
+#[derive(TableSchema, InsertDbEntity, UpdateDbEntity, SelectDbEntity, Debug)]
+pub struct UserProfileDto {
+    #[primary_key(0)]
+    pub id: i64,
+    #[json]
+    pub settings: UserSettings,
+    #[json]
+    pub previous_settings: Option<UserSettings>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereBySettingsModel {
+    #[json]
+    pub settings: UserSettings,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "user_profiles";
+
+    fn settings(theme: &str) -> UserSettings {
+        UserSettings {
+            theme: theme.to_string(),
+            notifications: true,
+            page_size: 50,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_column_round_trip() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<UserProfileDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let dto = UserProfileDto {
+            id: 1,
+            settings: settings("dark"),
+            previous_settings: None,
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        let result: UserProfileDto = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: 1 }))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(dto.settings, result.settings);
+        assert!(result.previous_settings.is_none());
+
+        let dto = UserProfileDto {
+            id: 1,
+            settings: settings("light"),
+            previous_settings: Some(settings("dark")),
+        };
+
+        connection
+            .insert_or_update_db_entity(TABLE_NAME, &dto)
+            .await
+            .unwrap();
+
+        let result: Option<UserProfileDto> = connection
+            .query_single_row(
+                TABLE_NAME,
+                Some(&WhereBySettingsModel {
+                    settings: settings("light"),
+                }),
+            )
+            .await
+            .unwrap();
+
+        let result = result.unwrap();
+
+        assert_eq!(dto.settings, result.settings);
+        assert_eq!(dto.previous_settings, result.previous_settings);
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use my_sqlite::{
+    macros::*,
+    sql_update::{JsonPatch, JsonRemove, JsonSet},
+};
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct SettingsDto {
+    #[primary_key(0)]
+    #[generate_where_model(name:"WhereByIdModel")]
+    pub id: i32,
+    #[sql_type("jsonb")]
+    pub settings: BTreeMap<String, String>,
+}
+
+#[derive(UpdateDbEntity)]
+pub struct SetSettingModel {
+    #[primary_key]
+    pub id: i32,
+    #[db_column_name("settings")]
+    pub theme: JsonSet<String>,
+}
+
+#[derive(UpdateDbEntity)]
+pub struct RemoveSettingModel {
+    #[primary_key]
+    pub id: i32,
+    #[db_column_name("settings")]
+    pub path: JsonRemove,
+}
+
+#[derive(UpdateDbEntity)]
+pub struct PatchSettingsModel {
+    #[primary_key]
+    pub id: i32,
+    #[db_column_name("settings")]
+    pub patch: JsonPatch<BTreeMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "settings";
+
+    #[tokio::test]
+    async fn test_json_set_remove_and_patch() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<SettingsDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let mut settings = BTreeMap::new();
+        settings.insert("lang".to_string(), "en".to_string());
+        settings.insert("legacy".to_string(), "1".to_string());
+
+        connection
+            .insert_db_entity(&SettingsDto { id: 1, settings }, TABLE_NAME)
+            .await
+            .unwrap();
+
+        connection
+            .update_db_entity(
+                &SetSettingModel {
+                    id: 1,
+                    theme: JsonSet::new("theme", "dark".to_string()),
+                },
+                TABLE_NAME,
+            )
+            .await
+            .unwrap();
+
+        connection
+            .update_db_entity(
+                &RemoveSettingModel {
+                    id: 1,
+                    path: JsonRemove::new("legacy"),
+                },
+                TABLE_NAME,
+            )
+            .await
+            .unwrap();
+
+        let mut patch = BTreeMap::new();
+        patch.insert("lang".to_string(), "de".to_string());
+
+        connection
+            .update_db_entity(
+                &PatchSettingsModel {
+                    id: 1,
+                    patch: JsonPatch(patch),
+                },
+                TABLE_NAME,
+            )
+            .await
+            .unwrap();
+
+        let result: SettingsDto = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: 1 }))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(2, result.settings.len());
+        assert_eq!("dark", result.settings.get("theme").unwrap());
+        assert_eq!("de", result.settings.get("lang").unwrap());
+        assert!(result.settings.get("legacy").is_none());
+    }
+}
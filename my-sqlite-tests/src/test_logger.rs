@@ -0,0 +1,121 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct ItemDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use my_sqlite::{
+        SqlLiteConnection, SqlLiteConnectionBuilder, SqlLiteLogger, SqlLogEvent, SqlLogLevel,
+        SqlOperation,
+    };
+
+    use super::*;
+
+    const TABLE_NAME: &str = "items";
+
+    #[derive(Debug)]
+    struct LoggedEvent {
+        level: SqlLogLevel,
+        operation: SqlOperation,
+        sql: String,
+        params_count: usize,
+        rows: Option<usize>,
+        has_error: bool,
+    }
+
+    #[derive(Default)]
+    struct CollectingLogger {
+        events: Mutex<Vec<LoggedEvent>>,
+    }
+
+    impl SqlLiteLogger for CollectingLogger {
+        fn log(&self, event: &SqlLogEvent) {
+            self.events.lock().unwrap().push(LoggedEvent {
+                level: event.level,
+                operation: event.operation,
+                sql: event.sql.to_string(),
+                params_count: event.params_count,
+                rows: event.rows,
+                has_error: event.error.is_some(),
+            });
+        }
+    }
+
+    async fn create_connection(
+        logger: Arc<CollectingLogger>,
+        log_level: SqlLogLevel,
+    ) -> SqlLiteConnection {
+        SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<ItemDto>(TABLE_NAME)
+            .logger(logger)
+            .log_level(log_level)
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_debug_level_logs_every_request() {
+        let logger = Arc::new(CollectingLogger::default());
+        let connection = create_connection(logger.clone(), SqlLogLevel::Debug).await;
+
+        let dto = ItemDto {
+            id: 1,
+            name: "first".to_string(),
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+
+        let items: Vec<ItemDto> = connection
+            .query_rows(TABLE_NAME, Some(&WhereByIdModel { id: 1 }))
+            .await
+            .unwrap();
+
+        assert_eq!(1, items.len());
+
+        let events = logger.events.lock().unwrap();
+        assert_eq!(2, events.len());
+
+        assert_eq!(SqlOperation::Insert, events[0].operation);
+        assert_eq!(SqlLogLevel::Debug, events[0].level);
+        assert_eq!(Some(1), events[0].rows);
+        assert!(events[0].params_count > 0);
+
+        assert_eq!(SqlOperation::Select, events[1].operation);
+        assert!(events[1].sql.starts_with("SELECT"));
+        assert_eq!(Some(1), events[1].rows);
+    }
+
+    #[tokio::test]
+    async fn test_error_level_logs_only_failures() {
+        let logger = Arc::new(CollectingLogger::default());
+        let connection = create_connection(logger.clone(), SqlLogLevel::Error).await;
+
+        let dto = ItemDto {
+            id: 1,
+            name: "first".to_string(),
+        };
+
+        connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+        let result = connection.insert_db_entity(&dto, TABLE_NAME).await;
+        assert!(result.is_err());
+
+        let events = logger.events.lock().unwrap();
+        assert_eq!(1, events.len());
+        assert_eq!(SqlLogLevel::Error, events[0].level);
+        assert!(events[0].has_error);
+        assert_eq!(None, events[0].rows);
+    }
+}
@@ -0,0 +1,75 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct MetricDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub value: i64,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{SqlLiteConnectionBuilder, SqlOperation};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "metrics";
+
+    #[tokio::test]
+    async fn test_metrics_snapshot() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<MetricDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        for id in 1..=2 {
+            connection
+                .insert_db_entity(&MetricDto { id, value: id }, TABLE_NAME)
+                .await
+                .unwrap();
+        }
+
+        let entities: Vec<MetricDto> = (3..=5).map(|id| MetricDto { id, value: id }).collect();
+
+        connection
+            .bulk_insert_db_entities(&entities, TABLE_NAME)
+            .await
+            .unwrap();
+
+        let result = connection
+            .insert_db_entity(&MetricDto { id: 1, value: 1 }, TABLE_NAME)
+            .await;
+        assert!(result.is_err());
+
+        let rows: Vec<MetricDto> = connection
+            .query_rows(TABLE_NAME, None::<&WhereByIdModel>)
+            .await
+            .unwrap();
+        assert_eq!(5, rows.len());
+
+        let snapshot = connection.get_metrics();
+
+        let inserts = snapshot.get(TABLE_NAME, SqlOperation::Insert).unwrap();
+        assert_eq!(3, inserts.calls);
+        assert_eq!(1, inserts.errors);
+        assert_eq!(2, inserts.rows_written);
+        assert_eq!(3, inserts.latency.count);
+
+        let bulk_inserts = snapshot.get(TABLE_NAME, SqlOperation::BulkInsert).unwrap();
+        assert_eq!(1, bulk_inserts.calls);
+        assert_eq!(3, bulk_inserts.rows_written);
+
+        let selects = snapshot.get(TABLE_NAME, SqlOperation::Select).unwrap();
+        assert_eq!(5, selects.rows_read);
+        assert_eq!(0, selects.rows_written);
+
+        assert_eq!(0, snapshot.queue_depth);
+        assert!(snapshot.max_queue_depth >= 1);
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use my_sqlite::macros::*;
+use types_reader::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct ScalarsDto {
+    #[primary_key(0)]
+    #[generate_where_model(name:"WhereByIdModel")]
+    pub id: i32,
+    pub v_u8: u8,
+    pub v_i8: i8,
+    pub v_u16: u16,
+    pub v_i16: i16,
+    pub v_u32: u32,
+    pub v_i32: i32,
+    pub v_u64: u64,
+    pub v_i64: i64,
+    pub v_usize: usize,
+    pub v_isize: isize,
+    pub v_f32: f32,
+    pub v_f64: f64,
+    pub v_bool: bool,
+    pub v_string: String,
+    #[sql_type("bigint")]
+    pub v_date_bigint: DateTimeAsMicroseconds,
+    #[sql_type("timestamp")]
+    pub v_date_timestamp: DateTimeAsMicroseconds,
+    pub v_vec: Vec<String>,
+    #[sql_type("jsonb")]
+    pub v_map: BTreeMap<String, String>,
+    pub o_u8: Option<u8>,
+    pub o_i8: Option<i8>,
+    pub o_u16: Option<u16>,
+    pub o_i16: Option<i16>,
+    pub o_u32: Option<u32>,
+    pub o_i32: Option<i32>,
+    pub o_u64: Option<u64>,
+    pub o_i64: Option<i64>,
+    pub o_usize: Option<usize>,
+    pub o_isize: Option<isize>,
+    pub o_f32: Option<f32>,
+    pub o_f64: Option<f64>,
+    pub o_bool: Option<bool>,
+    pub o_string: Option<String>,
+    #[sql_type("bigint")]
+    pub o_date_bigint: Option<DateTimeAsMicroseconds>,
+    #[sql_type("timestamp")]
+    pub o_date_timestamp: Option<DateTimeAsMicroseconds>,
+    pub o_vec: Option<Vec<String>>,
+    #[sql_type("jsonb")]
+    pub o_map: Option<BTreeMap<String, String>>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByNullableModel {
+    pub o_i32: Option<i32>,
+}
+
+impl ScalarsDto {
+    pub fn new(id: i32, with_optional: bool) -> Self {
+        let moment = DateTimeAsMicroseconds::from_str("2021-01-02T03:04:05.123456").unwrap();
+
+        let mut map = BTreeMap::new();
+        map.insert("key".to_string(), "value".to_string());
+
+        let vec = vec!["a".to_string(), "b".to_string()];
+
+        Self {
+            id,
+            v_u8: 250,
+            v_i8: -120,
+            v_u16: 65000,
+            v_i16: -32000,
+            v_u32: 4_000_000_000,
+            v_i32: -2_000_000_000,
+            v_u64: 9_000_000_000,
+            v_i64: -9_000_000_000,
+            v_usize: 123456,
+            v_isize: -123456,
+            v_f32: 1.5,
+            v_f64: -2.25,
+            v_bool: true,
+            v_string: "text".to_string(),
+            v_date_bigint: moment,
+            v_date_timestamp: moment,
+            v_vec: vec.clone(),
+            v_map: map.clone(),
+            o_u8: with_optional.then_some(250),
+            o_i8: with_optional.then_some(-120),
+            o_u16: with_optional.then_some(65000),
+            o_i16: with_optional.then_some(-32000),
+            o_u32: with_optional.then_some(4_000_000_000),
+            o_i32: with_optional.then_some(-2_000_000_000),
+            o_u64: with_optional.then_some(9_000_000_000),
+            o_i64: with_optional.then_some(-9_000_000_000),
+            o_usize: with_optional.then_some(123456),
+            o_isize: with_optional.then_some(-123456),
+            o_f32: with_optional.then_some(1.5),
+            o_f64: with_optional.then_some(-2.25),
+            o_bool: with_optional.then_some(false),
+            o_string: with_optional.then(|| "text".to_string()),
+            o_date_bigint: with_optional.then_some(moment),
+            o_date_timestamp: with_optional.then_some(moment),
+            o_vec: with_optional.then(|| vec.clone()),
+            o_map: with_optional.then(|| map.clone()),
+        }
+    }
+
+    pub fn assert_eq(&self, other: &Self) {
+        assert_eq!(self.id, other.id);
+        assert_eq!(self.v_u8, other.v_u8);
+        assert_eq!(self.v_i8, other.v_i8);
+        assert_eq!(self.v_u16, other.v_u16);
+        assert_eq!(self.v_i16, other.v_i16);
+        assert_eq!(self.v_u32, other.v_u32);
+        assert_eq!(self.v_i32, other.v_i32);
+        assert_eq!(self.v_u64, other.v_u64);
+        assert_eq!(self.v_i64, other.v_i64);
+        assert_eq!(self.v_usize, other.v_usize);
+        assert_eq!(self.v_isize, other.v_isize);
+        assert_eq!(self.v_f32, other.v_f32);
+        assert_eq!(self.v_f64, other.v_f64);
+        assert_eq!(self.v_bool, other.v_bool);
+        assert_eq!(self.v_string, other.v_string);
+        assert_eq!(
+            self.v_date_bigint.unix_microseconds,
+            other.v_date_bigint.unix_microseconds
+        );
+        assert_eq!(
+            self.v_date_timestamp.unix_microseconds,
+            other.v_date_timestamp.unix_microseconds
+        );
+        assert_eq!(self.v_vec, other.v_vec);
+        assert_eq!(self.v_map, other.v_map);
+        assert_eq!(self.o_u8, other.o_u8);
+        assert_eq!(self.o_i8, other.o_i8);
+        assert_eq!(self.o_u16, other.o_u16);
+        assert_eq!(self.o_i16, other.o_i16);
+        assert_eq!(self.o_u32, other.o_u32);
+        assert_eq!(self.o_i32, other.o_i32);
+        assert_eq!(self.o_u64, other.o_u64);
+        assert_eq!(self.o_i64, other.o_i64);
+        assert_eq!(self.o_usize, other.o_usize);
+        assert_eq!(self.o_isize, other.o_isize);
+        assert_eq!(self.o_f32, other.o_f32);
+        assert_eq!(self.o_f64, other.o_f64);
+        assert_eq!(self.o_bool, other.o_bool);
+        assert_eq!(self.o_string, other.o_string);
+        assert_eq!(
+            self.o_date_bigint.map(|itm| itm.unix_microseconds),
+            other.o_date_bigint.map(|itm| itm.unix_microseconds)
+        );
+        assert_eq!(
+            self.o_date_timestamp.map(|itm| itm.unix_microseconds),
+            other.o_date_timestamp.map(|itm| itm.unix_microseconds)
+        );
+        assert_eq!(self.o_vec, other.o_vec);
+        assert_eq!(self.o_map, other.o_map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::SqlLiteConnectionBuilder;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "scalars";
+
+    #[tokio::test]
+    async fn test_round_trip_with_and_without_optional_values() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<ScalarsDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        for (id, with_optional) in [(1, true), (2, false)] {
+            let src = ScalarsDto::new(id, with_optional);
+
+            connection.insert_db_entity(&src, TABLE_NAME).await.unwrap();
+
+            let result: ScalarsDto = connection
+                .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id }))
+                .await
+                .unwrap()
+                .unwrap();
+
+            src.assert_eq(&result);
+        }
+
+        let result: Vec<ScalarsDto> = connection
+            .query_rows(TABLE_NAME, Some(&WhereByNullableModel { o_i32: None }))
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(2, result[0].id);
+    }
+}
@@ -0,0 +1,139 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct ExportItemDto {
+    #[primary_key(0)]
+    pub account_id: String,
+    #[primary_key(1)]
+    pub id: i64,
+    pub amount: f64,
+}
+
+#[derive(TableSchema, SelectDbEntity, Debug)]
+pub struct ExportAccountDto {
+    #[primary_key(0)]
+    #[group_by]
+    pub account_id: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereAmountModel {
+    #[operator(">=")]
+    pub amount: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use my_sqlite::{sql_where::NoneWhereModel, SqlLiteError};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "export_items";
+
+    fn get_entities() -> Vec<ExportItemDto> {
+        let mut entities = Vec::new();
+
+        for account_id in ["b", "a"] {
+            for id in 0..5 {
+                entities.push(ExportItemDto {
+                    account_id: account_id.to_string(),
+                    id,
+                    amount: id as f64,
+                });
+            }
+        }
+
+        entities
+    }
+
+    #[tokio::test]
+    async fn test_chunks_cover_all_rows_in_primary_key_order() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let mut reader = connection
+            .query_rows_as_chunks::<ExportItemDto, NoneWhereModel>(TABLE_NAME, None, 3)
+            .unwrap();
+
+        let mut chunk_sizes = Vec::new();
+        let mut keys = Vec::new();
+
+        while let Some(chunk) = reader.get_next().await {
+            let chunk = chunk.unwrap();
+            chunk_sizes.push(chunk.len());
+
+            for itm in chunk {
+                keys.push(format!("{}:{}", itm.account_id, itm.id));
+            }
+        }
+
+        assert_eq!(chunk_sizes, vec![3, 3, 3, 1]);
+        assert_eq!(
+            keys,
+            vec!["a:0", "a:1", "a:2", "a:3", "a:4", "b:0", "b:1", "b:2", "b:3", "b:4"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunks_apply_where_model() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereAmountModel { amount: 3.0 };
+
+        let mut reader = connection
+            .query_rows_as_chunks::<ExportItemDto, _>(TABLE_NAME, Some(&where_model), 2)
+            .unwrap();
+
+        let mut items = Vec::new();
+
+        while let Some(chunk) = reader.get_next().await {
+            items.extend(chunk.unwrap());
+        }
+
+        assert_eq!(items.len(), 4);
+        assert!(items.iter().all(|itm| itm.amount >= 3.0));
+    }
+
+    #[tokio::test]
+    async fn test_chunks_as_stream() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let chunks: Vec<_> = connection
+            .query_rows_as_chunks::<ExportItemDto, NoneWhereModel>(TABLE_NAME, None, 4)
+            .unwrap()
+            .collect()
+            .await;
+
+        let chunk_sizes: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| chunk.unwrap().len())
+            .collect();
+
+        assert_eq!(chunk_sizes, vec![4, 4, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_chunks_reject_group_by_entities() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let mut reader = connection
+            .query_rows_as_chunks::<ExportAccountDto, NoneWhereModel>(TABLE_NAME, None, 4)
+            .unwrap();
+
+        assert!(matches!(
+            reader.get_next().await,
+            Some(Err(SqlLiteError::InvalidArgument { .. }))
+        ));
+        assert!(reader.get_next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_chunks_reject_zero_chunk_size() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result =
+            connection.query_rows_as_chunks::<ExportItemDto, NoneWhereModel>(TABLE_NAME, None, 0);
+
+        assert!(matches!(result, Err(SqlLiteError::InvalidArgument { .. })));
+    }
+}
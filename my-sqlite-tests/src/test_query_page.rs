@@ -0,0 +1,127 @@
+use my_sqlite::{macros::*, GroupByCount};
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct CustomerDto {
+    #[primary_key(0)]
+    #[order_by]
+    pub id: i64,
+    pub country: String,
+}
+
+#[derive(SelectDbEntity, Debug)]
+pub struct CustomersByCountryDto {
+    #[group_by]
+    #[order_by]
+    pub country: String,
+    pub customers: GroupByCount<i64>,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByCountryModel {
+    pub country: String,
+    #[limit]
+    pub limit: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{sql_where::NoneWhereModel, SqlLiteError};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "customers";
+
+    fn get_entities() -> Vec<CustomerDto> {
+        (1..=7)
+            .map(|id| CustomerDto {
+                id,
+                country: if id <= 5 { "UA" } else { "PL" }.to_string(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_last_page() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result = connection
+            .query_page::<CustomerDto, NoneWhereModel>(TABLE_NAME, None, 3, 3)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 7);
+        assert_eq!(result.page, 3);
+        assert_eq!(result.page_size, 3);
+        assert_eq!(result.get_pages_count(), 3);
+        assert!(!result.has_next_page());
+
+        let ids: Vec<i64> = result.items.iter().map(|itm| itm.id).collect();
+        assert_eq!(ids, vec![7]);
+    }
+
+    #[tokio::test]
+    async fn test_where_limit_is_ignored() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereByCountryModel {
+            country: "UA".to_string(),
+            limit: 1,
+        };
+
+        let result = connection
+            .query_page::<CustomerDto, _>(TABLE_NAME, Some(&where_model), 1, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 5);
+        assert!(result.has_next_page());
+
+        let ids: Vec<i64> = result.items.iter().map(|itm| itm.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_total_counts_groups() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result = connection
+            .query_page::<CustomersByCountryDto, NoneWhereModel>(TABLE_NAME, None, 1, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 2);
+        assert!(result.has_next_page());
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].country, "PL");
+        assert_eq!(result.items[0].customers.get_value(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_offset_overflow() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result = connection
+            .query_page::<CustomerDto, NoneWhereModel>(TABLE_NAME, None, usize::MAX, 2)
+            .await;
+
+        assert!(matches!(result, Err(SqlLiteError::InvalidArgument { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_zero_page_and_page_size_are_rejected() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result = connection
+            .query_page::<CustomerDto, NoneWhereModel>(TABLE_NAME, None, 0, 2)
+            .await;
+
+        assert!(matches!(result, Err(SqlLiteError::InvalidArgument { .. })));
+
+        let result = connection
+            .query_page::<CustomerDto, NoneWhereModel>(TABLE_NAME, None, 1, 0)
+            .await;
+
+        assert!(matches!(result, Err(SqlLiteError::InvalidArgument { .. })));
+    }
+}
@@ -0,0 +1,197 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct AuditRecordDto {
+    #[primary_key(0)]
+    #[order_by_desc]
+    pub created: i64,
+    #[primary_key(1)]
+    #[order_by_desc]
+    pub id: String,
+    pub user_id: String,
+}
+
+#[derive(TableSchema, SelectDbEntity, Debug)]
+pub struct AuditRecordByUserDto {
+    #[primary_key(0)]
+    pub created: i64,
+    #[primary_key(1)]
+    pub id: String,
+    #[order_by]
+    pub user_id: String,
+}
+
+#[derive(SelectDbEntity, Debug)]
+pub struct AuditUserSummaryDto {
+    #[group_by]
+    #[order_by]
+    pub user_id: String,
+    #[group_by]
+    #[order_by]
+    pub created: i64,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByUserModel {
+    pub user_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{
+        sql_select::{CursorPageEntity, SelectEntity},
+        sql_where::NoneWhereModel,
+        table_schema::TableSchemaProvider,
+        SqlLiteConnection, SqlLiteError,
+    };
+
+    use super::*;
+
+    const TABLE_NAME: &str = "audit_records";
+
+    fn get_entities() -> Vec<AuditRecordDto> {
+        let mut entities = Vec::new();
+
+        for no in 0..7 {
+            entities.push(AuditRecordDto {
+                created: no / 3,
+                id: format!("id-{}", no),
+                user_id: if no % 2 == 0 { "even" } else { "odd" }.to_string(),
+            });
+        }
+
+        entities
+    }
+
+    async fn read_all_pages<
+        TEntity: CursorPageEntity + TableSchemaProvider + Send + Sync + 'static,
+    >(
+        connection: &SqlLiteConnection,
+        page_size: usize,
+        get_id: impl Fn(&TEntity) -> String,
+    ) -> Vec<Vec<String>> {
+        let mut cursor: Option<String> = None;
+        let mut pages = Vec::new();
+
+        loop {
+            let page = connection
+                .query_page_by_cursor::<TEntity, NoneWhereModel>(
+                    TABLE_NAME,
+                    None,
+                    cursor.as_deref(),
+                    page_size,
+                )
+                .await
+                .unwrap();
+
+            pages.push(page.items.iter().map(&get_id).collect::<Vec<_>>());
+
+            cursor = page.next_cursor;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        pages
+    }
+
+    #[test]
+    fn test_generated_order_by_and_group_by() {
+        assert_eq!(
+            AuditRecordDto::get_order_by_fields(),
+            Some(" ORDER BY created DESC,id DESC")
+        );
+
+        assert_eq!(
+            AuditUserSummaryDto::get_order_by_fields(),
+            Some(" ORDER BY user_id,created")
+        );
+
+        assert_eq!(
+            AuditUserSummaryDto::get_group_by_fields(),
+            Some(" GROUP BY user_id,created")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pages_follow_compound_desc_order() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let pages = read_all_pages::<AuditRecordDto>(&connection, 3, |itm| itm.id.clone()).await;
+
+        assert_eq!(
+            pages,
+            vec![
+                vec!["id-6", "id-5", "id-4"],
+                vec!["id-3", "id-2", "id-1"],
+                vec!["id-0"],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_primary_key_breaks_order_by_ties() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let pages =
+            read_all_pages::<AuditRecordByUserDto>(&connection, 2, |itm| itm.id.clone()).await;
+
+        assert_eq!(
+            pages,
+            vec![
+                vec!["id-0", "id-2"],
+                vec!["id-4", "id-6"],
+                vec!["id-1", "id-3"],
+                vec!["id-5"],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_zero_page_size_is_rejected() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result = connection
+            .query_page_by_cursor::<AuditRecordDto, NoneWhereModel>(TABLE_NAME, None, None, 0)
+            .await;
+
+        assert!(matches!(result, Err(SqlLiteError::InvalidArgument { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_last_full_page_has_no_next_cursor() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereByUserModel {
+            user_id: "odd".to_string(),
+        };
+
+        let page = connection
+            .query_page_by_cursor::<AuditRecordDto, _>(TABLE_NAME, Some(&where_model), None, 3)
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 3);
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_cursor() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result = connection
+            .query_page_by_cursor::<AuditRecordDto, NoneWhereModel>(
+                TABLE_NAME,
+                None,
+                Some("not-a-cursor"),
+                3,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(my_sqlite::SqlLiteError::InvalidCursor { .. })
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct EventDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use futures::StreamExt;
+    use my_sqlite::{SqlLiteConnection, SqlLiteConnectionBuilder, SqliteQueryStream};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "events";
+
+    async fn create_connection(rows_count: i64) -> SqlLiteConnection {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<EventDto>(TABLE_NAME)
+            .stream_channel_capacity(4)
+            .build()
+            .await
+            .unwrap();
+
+        let entities: Vec<_> = (0..rows_count)
+            .map(|id| EventDto {
+                id,
+                name: format!("event-{}", id),
+            })
+            .collect();
+
+        connection
+            .bulk_insert_db_entities(&entities, TABLE_NAME)
+            .await
+            .unwrap();
+
+        connection
+    }
+
+    #[tokio::test]
+    async fn test_stream_reads_all_rows() {
+        let connection = create_connection(100).await;
+
+        let stream: SqliteQueryStream<EventDto> = connection
+            .query_rows_as_stream::<EventDto, WhereByIdModel>(TABLE_NAME, None)
+            .await;
+
+        let items: Vec<EventDto> = stream.map(|itm| itm.unwrap()).collect().await;
+
+        assert_eq!(items.len(), 100);
+        assert_eq!(items[42].name, "event-42");
+    }
+
+    #[tokio::test]
+    async fn test_prepare_error_is_delivered_as_item() {
+        let connection = create_connection(1).await;
+
+        let mut stream: SqliteQueryStream<EventDto> = connection
+            .query_rows_as_stream::<EventDto, WhereByIdModel>("missing_table", None)
+            .await;
+
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert!(err.get_sql().unwrap().contains("missing_table"));
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dropped_stream_releases_connection() {
+        let connection = create_connection(1000).await;
+
+        let mut stream: SqliteQueryStream<EventDto> = connection
+            .query_rows_as_stream::<EventDto, WhereByIdModel>(TABLE_NAME, None)
+            .await;
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.id, 0);
+
+        drop(stream);
+
+        let started = Instant::now();
+
+        let result: Option<EventDto> = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: 999 }))
+            .await
+            .unwrap();
+
+        assert_eq!(result.unwrap().id, 999);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,110 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct JobDto {
+    #[primary_key(0)]
+    pub id: i64,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[where_raw_model("id >= ${min_id} AND (WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x+1 FROM c WHERE x < 1000000000) SELECT count(*) FROM c) > 0")]
+pub struct WhereRunawayModel {
+    pub min_id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use my_sqlite::{SqlLiteConnection, SqlLiteConnectionBuilder, SqlLiteError};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "jobs";
+
+    async fn create_connection(builder: SqlLiteConnectionBuilder) -> SqlLiteConnection {
+        let connection = builder
+            .create_table_if_no_exists::<JobDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        connection
+            .insert_db_entity(&JobDto { id: 1 }, TABLE_NAME)
+            .await
+            .unwrap();
+
+        connection
+    }
+
+    async fn assert_connection_is_free(connection: &SqlLiteConnection) {
+        let started = Instant::now();
+
+        let result: Option<JobDto> = connection
+            .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id: 1 }))
+            .await
+            .unwrap();
+
+        assert!(result.is_some());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_connection_default_timeout() {
+        let connection = create_connection(
+            SqlLiteConnectionBuilder::new(":memory:").query_timeout(Duration::from_millis(100)),
+        )
+        .await;
+
+        let result: Result<Vec<JobDto>, SqlLiteError> = connection
+            .query_rows(TABLE_NAME, Some(&WhereRunawayModel { min_id: 0 }))
+            .await;
+
+        match result {
+            Err(SqlLiteError::Timeout { sql }) => assert!(sql.unwrap().starts_with("SELECT")),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert_connection_is_free(&connection).await;
+    }
+
+    #[tokio::test]
+    async fn test_per_call_timeout_interrupts_dropped_future() {
+        let connection = create_connection(SqlLiteConnectionBuilder::new(":memory:")).await;
+
+        let result: Result<Vec<JobDto>, SqlLiteError> = connection
+            .with_timeout(
+                Duration::from_millis(100),
+                connection.query_rows(TABLE_NAME, Some(&WhereRunawayModel { min_id: 0 })),
+            )
+            .await;
+
+        assert!(matches!(result, Err(SqlLiteError::Timeout { .. })));
+
+        assert_connection_is_free(&connection).await;
+    }
+
+    #[tokio::test]
+    async fn test_manual_interrupt_is_not_a_timeout() {
+        let connection = create_connection(SqlLiteConnectionBuilder::new(":memory:")).await;
+
+        let (result, _) = tokio::join!(
+            connection.query_rows::<JobDto, _>(TABLE_NAME, Some(&WhereRunawayModel { min_id: 0 })),
+            async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                connection.interrupt();
+            }
+        );
+
+        match result {
+            Err(SqlLiteError::Interrupted { sql }) => assert!(sql.unwrap().starts_with("SELECT")),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        assert_connection_is_free(&connection).await;
+    }
+}
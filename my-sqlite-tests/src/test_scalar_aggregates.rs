@@ -0,0 +1,166 @@
+use my_sqlite::macros::*;
+use types_reader::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct TradeDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub instrument: String,
+    pub volume: f64,
+    pub executed_at: i64,
+    #[sql_type("bigint")]
+    pub settled_at: DateTimeAsMicroseconds,
+    #[sql_type("timestamp")]
+    pub confirmed_at: DateTimeAsMicroseconds,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByInstrumentModel {
+    pub instrument: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::sql_where::NoneWhereModel;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "trades";
+
+    const MOMENT: i64 = 1_700_000_000_000_000;
+
+    fn get_entities() -> Vec<TradeDto> {
+        vec![
+            TradeDto {
+                id: 1,
+                instrument: "EURUSD".to_string(),
+                volume: 1.5,
+                executed_at: 100,
+                settled_at: DateTimeAsMicroseconds::new(MOMENT + 1_000_123),
+                confirmed_at: DateTimeAsMicroseconds::new(MOMENT + 1_500_000),
+            },
+            TradeDto {
+                id: 2,
+                instrument: "EURUSD".to_string(),
+                volume: 2.5,
+                executed_at: 300,
+                settled_at: DateTimeAsMicroseconds::new(MOMENT + 2_000_123),
+                confirmed_at: DateTimeAsMicroseconds::new(MOMENT + 2_500_000),
+            },
+            TradeDto {
+                id: 3,
+                instrument: "BTCUSD".to_string(),
+                volume: 0.5,
+                executed_at: 200,
+                settled_at: DateTimeAsMicroseconds::new(MOMENT + 3_000_123),
+                confirmed_at: DateTimeAsMicroseconds::new(MOMENT + 3_500_000),
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_min_max() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let latest: Option<i64> = connection
+            .max::<_, NoneWhereModel>(TABLE_NAME, "executed_at", None)
+            .await
+            .unwrap();
+
+        assert_eq!(latest, Some(300));
+
+        let where_model = WhereByInstrumentModel {
+            instrument: "BTCUSD".to_string(),
+        };
+
+        let first: Option<u64> = connection
+            .min(TABLE_NAME, "executed_at", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(first, Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_sum_avg() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereByInstrumentModel {
+            instrument: "EURUSD".to_string(),
+        };
+
+        let sum: Option<f64> = connection
+            .sum(TABLE_NAME, "volume", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(sum, Some(4.0));
+
+        let avg: Option<f64> = connection
+            .avg(TABLE_NAME, "volume", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(avg, Some(2.0));
+    }
+
+    #[tokio::test]
+    async fn test_empty_set_returns_none() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let where_model = WhereByInstrumentModel {
+            instrument: "XAUUSD".to_string(),
+        };
+
+        let latest: Option<i64> = connection
+            .max(TABLE_NAME, "executed_at", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert!(latest.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_date_time_aggregates() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let latest: Option<DateTimeAsMicroseconds> = connection
+            .max::<_, NoneWhereModel>(TABLE_NAME, "settled_at", None)
+            .await
+            .unwrap();
+
+        assert_eq!(latest.unwrap().unix_microseconds, MOMENT + 3_000_123);
+
+        let first: Option<DateTimeAsMicroseconds> = connection
+            .min::<_, NoneWhereModel>(TABLE_NAME, "confirmed_at", None)
+            .await
+            .unwrap();
+
+        assert_eq!(first.unwrap().unix_microseconds, MOMENT + 1_500_000);
+
+        let where_model = WhereByInstrumentModel {
+            instrument: "EURUSD".to_string(),
+        };
+
+        let avg: Option<DateTimeAsMicroseconds> = connection
+            .avg(TABLE_NAME, "settled_at", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(avg.unwrap().unix_microseconds, MOMENT + 1_500_123);
+
+        let avg: Option<DateTimeAsMicroseconds> = connection
+            .avg(TABLE_NAME, "confirmed_at", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(avg.unwrap().unix_microseconds, MOMENT + 2_000_000);
+
+        let sum: Option<DateTimeAsMicroseconds> = connection
+            .sum(TABLE_NAME, "confirmed_at", Some(&where_model))
+            .await
+            .unwrap();
+
+        assert_eq!(sum.unwrap().unix_microseconds, 2 * MOMENT + 4_000_000);
+    }
+}
@@ -0,0 +1,109 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct OrderDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub customer: String,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByCustomerModel<'s> {
+    pub customer: &'s str,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use my_sqlite::{
+        QueryPlan, SqlLiteConnectionBuilder, SqlLiteLogger, SqlLogEvent, SqlLogLevel,
+    };
+
+    use super::*;
+
+    const TABLE_NAME: &str = "orders";
+
+    #[derive(Default)]
+    struct SlowQueryLogger {
+        events: Mutex<Vec<(Vec<String>, QueryPlan)>>,
+    }
+
+    impl SqlLiteLogger for SlowQueryLogger {
+        fn log(&self, event: &SqlLogEvent) {
+            if event.level != SqlLogLevel::Warn {
+                return;
+            }
+
+            self.events.lock().unwrap().push((
+                event.params.unwrap_or_default().to_vec(),
+                event.query_plan.cloned().unwrap_or_default(),
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_query_is_logged_with_plan() {
+        let logger = Arc::new(SlowQueryLogger::default());
+
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<OrderDto>(TABLE_NAME)
+            .logger(logger.clone())
+            .slow_query_threshold(Duration::ZERO)
+            .build()
+            .await
+            .unwrap();
+
+        let result: Vec<OrderDto> = connection
+            .query_rows(
+                TABLE_NAME,
+                Some(&WhereByCustomerModel { customer: "alice" }),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.is_empty());
+
+        let events = logger.events.lock().unwrap();
+        assert_eq!(1, events.len());
+
+        let (params, plan) = &events[0];
+        assert_eq!(vec!["'alice'".to_string()], *params);
+        assert!(plan.has_full_scan());
+    }
+
+    #[tokio::test]
+    async fn test_explain() {
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<OrderDto>(TABLE_NAME)
+            .build()
+            .await
+            .unwrap();
+
+        let plan = connection
+            .explain::<OrderDto, WhereByIdModel>(TABLE_NAME, Some(&WhereByIdModel { id: 1 }))
+            .await
+            .unwrap();
+
+        assert!(!plan.steps.is_empty());
+        assert!(!plan.has_full_scan());
+
+        let plan = connection
+            .explain::<OrderDto, WhereByCustomerModel>(
+                TABLE_NAME,
+                Some(&WhereByCustomerModel { customer: "alice" }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, plan.get_full_scans().len());
+    }
+}
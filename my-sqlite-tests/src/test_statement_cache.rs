@@ -0,0 +1,98 @@
+use my_sqlite::macros::*;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct AccountDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub balance: f64,
+    pub level: u8,
+}
+
+#[derive(WhereDbModel)]
+pub struct WhereByIdModel {
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use my_sqlite::{
+        SqlLiteConnectionBuilder, SqlLiteLogger, SqlLogEvent, SqlLogLevel, SqlOperation,
+    };
+
+    use super::*;
+
+    const TABLE_NAME: &str = "accounts";
+
+    #[derive(Default)]
+    struct SqlCollector {
+        sql: Mutex<Vec<(SqlOperation, String)>>,
+    }
+
+    impl SqlLiteLogger for SqlCollector {
+        fn log(&self, event: &SqlLogEvent) {
+            self.sql
+                .lock()
+                .unwrap()
+                .push((event.operation, event.sql.to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_same_shape_produces_same_sql() {
+        let collector = Arc::new(SqlCollector::default());
+
+        let connection = SqlLiteConnectionBuilder::new(":memory:")
+            .create_table_if_no_exists::<AccountDto>(TABLE_NAME)
+            .statement_cache_capacity(4)
+            .logger(collector.clone())
+            .log_level(SqlLogLevel::Debug)
+            .build()
+            .await
+            .unwrap();
+
+        for id in 1..=3 {
+            let dto = AccountDto {
+                id,
+                balance: id as f64 * 1.5,
+                level: id as u8,
+            };
+
+            connection.insert_db_entity(&dto, TABLE_NAME).await.unwrap();
+        }
+
+        for id in 1..=3 {
+            let result: Option<AccountDto> = connection
+                .query_single_row(TABLE_NAME, Some(&WhereByIdModel { id }))
+                .await
+                .unwrap();
+
+            let result = result.unwrap();
+            assert_eq!(id, result.id);
+            assert_eq!(id as f64 * 1.5, result.balance);
+            assert_eq!(id as u8, result.level);
+        }
+
+        let sql = collector.sql.lock().unwrap();
+
+        let inserts: Vec<&String> = sql
+            .iter()
+            .filter(|(operation, _)| *operation == SqlOperation::Insert)
+            .map(|(_, sql)| sql)
+            .collect();
+
+        let selects: Vec<&String> = sql
+            .iter()
+            .filter(|(operation, _)| *operation == SqlOperation::SelectSingle)
+            .map(|(_, sql)| sql)
+            .collect();
+
+        assert_eq!(3, inserts.len());
+        assert!(inserts.iter().all(|itm| *itm == inserts[0]));
+
+        assert_eq!(3, selects.len());
+        assert!(selects.iter().all(|itm| *itm == selects[0]));
+        assert!(selects[0].contains("$1"));
+    }
+}
@@ -0,0 +1,150 @@
+use my_sqlite::{macros::*, GroupByCount, GroupByTimeBucket};
+use types_reader::rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct LogItemDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub level: String,
+    #[sql_type("bigint")]
+    pub moment: DateTimeAsMicroseconds,
+    #[sql_type("timestamp")]
+    pub moment_as_text: DateTimeAsMicroseconds,
+}
+
+#[derive(SelectDbEntity)]
+pub struct LogsPerHourDto {
+    #[db_column_name("moment")]
+    #[sql_type("bigint")]
+    #[time_bucket("hour")]
+    pub hour: GroupByTimeBucket,
+    #[group_by]
+    pub level: String,
+    pub count: GroupByCount<i64>,
+}
+
+#[derive(SelectDbEntity)]
+pub struct LogsPerQuarterDto {
+    #[db_column_name("moment_as_text")]
+    #[sql_type("timestamp")]
+    #[time_bucket("15m")]
+    pub quarter: GroupByTimeBucket,
+    pub count: GroupByCount<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::sql_where::NoneWhereModel;
+
+    use super::*;
+
+    const TABLE_NAME: &str = "logs";
+
+    fn get_entities() -> Vec<LogItemDto> {
+        let items = [
+            ("ERROR", "2024-05-01T10:05:00"),
+            ("ERROR", "2024-05-01T10:20:00"),
+            ("INFO", "2024-05-01T10:59:59.999999"),
+            ("ERROR", "2024-05-01T11:00:00"),
+            ("INFO", "2024-05-01T11:14:00"),
+        ];
+
+        items
+            .iter()
+            .enumerate()
+            .map(|(id, (level, moment))| {
+                let moment = DateTimeAsMicroseconds::from_str(moment).unwrap();
+                LogItemDto {
+                    id: id as i64,
+                    level: level.to_string(),
+                    moment,
+                    moment_as_text: moment,
+                }
+            })
+            .collect()
+    }
+
+    fn hour(src: &str) -> i64 {
+        DateTimeAsMicroseconds::from_str(src).unwrap().unix_microseconds
+    }
+
+    #[tokio::test]
+    async fn test_counts_per_level_per_hour() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let mut result: Vec<LogsPerHourDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        result.sort_by_key(|itm| (itm.level.clone(), itm.hour.get_value().unix_microseconds));
+
+        let result: Vec<_> = result
+            .iter()
+            .map(|itm| {
+                (
+                    itm.level.as_str(),
+                    itm.hour.get_value().unix_microseconds,
+                    itm.count.get_value(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                ("ERROR", hour("2024-05-01T10:00:00"), 2),
+                ("ERROR", hour("2024-05-01T11:00:00"), 1),
+                ("INFO", hour("2024-05-01T10:00:00"), 1),
+                ("INFO", hour("2024-05-01T11:00:00"), 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_width_over_timestamp_storage() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let mut result: Vec<LogsPerQuarterDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        result.sort_by_key(|itm| itm.quarter.get_value().unix_microseconds);
+
+        let result: Vec<_> = result
+            .iter()
+            .map(|itm| (itm.quarter.get_value().unix_microseconds, itm.count.get_value()))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                (hour("2024-05-01T10:00:00"), 1),
+                (hour("2024-05-01T10:15:00"), 1),
+                (hour("2024-05-01T10:45:00"), 1),
+                (hour("2024-05-01T11:00:00"), 2),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bucket_before_unix_epoch_is_floored() {
+        let entities = vec![LogItemDto {
+            id: 0,
+            level: "ERROR".to_string(),
+            moment: DateTimeAsMicroseconds::new(-30 * 60 * 1_000_000),
+            moment_as_text: DateTimeAsMicroseconds::new(0),
+        }];
+
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &entities).await;
+
+        let result: Vec<LogsPerHourDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].hour.get_value().unix_microseconds, -3_600_000_000);
+    }
+}
@@ -0,0 +1,145 @@
+use my_sqlite::{macros::*, DenseRank, Lag, Lead, Rank, RowNumber, RunningSum};
+
+#[derive(TableSchema, InsertDbEntity, SelectDbEntity, Debug)]
+pub struct ReadingDto {
+    #[primary_key(0)]
+    pub id: i64,
+    pub device_id: String,
+    pub moment: i64,
+    pub value: f64,
+}
+
+#[derive(SelectDbEntity)]
+pub struct ReadingDeltaDto {
+    #[order_by]
+    pub id: i64,
+    pub device_id: String,
+    pub value: f64,
+    #[window("PARTITION BY device_id ORDER BY moment DESC")]
+    pub row_no: RowNumber,
+    #[sql("value - lag(value)")]
+    #[window("PARTITION BY device_id ORDER BY moment")]
+    pub delta: Option<f64>,
+    #[db_column_name("moment")]
+    #[window("PARTITION BY device_id ORDER BY moment")]
+    pub prev_moment: Lag<i64>,
+}
+
+#[derive(SelectDbEntity)]
+pub struct ReadingTotalsDto {
+    #[order_by]
+    pub id: i64,
+    #[db_column_name("value")]
+    #[window("PARTITION BY device_id ORDER BY moment")]
+    pub running_total: RunningSum<f64>,
+    #[db_column_name("moment")]
+    #[window("PARTITION BY device_id ORDER BY moment")]
+    pub next_moment: Lead<i64>,
+    #[window("ORDER BY device_id")]
+    pub device_rank: DenseRank,
+    #[window("ORDER BY device_id")]
+    pub rank: Rank,
+    #[sql("value * 2")]
+    pub doubled_value: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use my_sqlite::{sql_select::SelectEntity, sql_where::NoneWhereModel};
+
+    use super::*;
+
+    const TABLE_NAME: &str = "readings";
+
+    fn get_entities() -> Vec<ReadingDto> {
+        let items = [
+            (1, "dev-1", 100, 1.0),
+            (2, "dev-1", 200, 4.0),
+            (3, "dev-2", 150, 10.0),
+            (4, "dev-1", 300, 2.5),
+            (5, "dev-2", 250, 7.0),
+        ];
+
+        items
+            .iter()
+            .map(|(id, device_id, moment, value)| ReadingDto {
+                id: *id,
+                device_id: device_id.to_string(),
+                moment: *moment,
+                value: *value,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_latest_row_per_device_and_deltas() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result: Vec<ReadingDeltaDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        let row_numbers: Vec<_> = result.iter().map(|itm| itm.row_no.get_value()).collect();
+        assert_eq!(row_numbers, vec![3, 2, 2, 1, 1]);
+
+        let deltas: Vec<_> = result.iter().map(|itm| itm.delta).collect();
+        assert_eq!(deltas, vec![None, Some(3.0), None, Some(-1.5), Some(-3.0)]);
+
+        let prev_moments: Vec<_> = result
+            .iter()
+            .map(|itm| itm.prev_moment.get_value())
+            .collect();
+        assert_eq!(prev_moments, vec![None, Some(100), None, Some(200), Some(150)]);
+
+        let latest: Vec<_> = result
+            .iter()
+            .filter(|itm| itm.row_no.get_value() == 1)
+            .map(|itm| (itm.device_id.as_str(), itm.value))
+            .collect();
+        assert_eq!(latest, vec![("dev-1", 2.5), ("dev-2", 7.0)]);
+    }
+
+    #[tokio::test]
+    async fn test_running_sum_lead_and_rank() {
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result: Vec<ReadingTotalsDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        let totals: Vec<_> = result
+            .iter()
+            .map(|itm| itm.running_total.get_value())
+            .collect();
+        assert_eq!(totals, vec![1.0, 5.0, 10.0, 7.5, 17.0]);
+
+        let next_moments: Vec<_> = result
+            .iter()
+            .map(|itm| itm.next_moment.get_value())
+            .collect();
+        assert_eq!(next_moments, vec![Some(200), Some(300), Some(250), None, None]);
+
+        let ranks: Vec<_> = result
+            .iter()
+            .map(|itm| (itm.device_rank.get_value(), itm.rank.get_value()))
+            .collect();
+        assert_eq!(ranks, vec![(1, 1), (1, 1), (2, 4), (1, 1), (2, 4)]);
+    }
+
+    #[tokio::test]
+    async fn test_plain_sql_expression_without_window() {
+        assert!(ReadingTotalsDto::SELECT_FIELDS.contains(&"doubled_value"));
+
+        let connection = crate::create_connection_with_entities(TABLE_NAME, &get_entities()).await;
+
+        let result: Vec<ReadingTotalsDto> = connection
+            .query_rows::<_, NoneWhereModel>(TABLE_NAME, None)
+            .await
+            .unwrap();
+
+        let doubled: Vec<_> = result.iter().map(|itm| itm.doubled_value).collect();
+        assert_eq!(doubled, vec![2.0, 8.0, 20.0, 5.0, 14.0]);
+    }
+}